@@ -53,7 +53,11 @@ bench_binop_deref!(_bench_matrix4_div_s, Matrix4<f32>, f32, div_s);
 bench_unop!(_bench_matrix2_invert, Matrix2<f32>, invert);
 bench_unop!(_bench_matrix3_invert, Matrix3<f32>, invert);
 bench_unop!(_bench_matrix4_invert, Matrix4<f32>, invert);
+bench_unop!(_bench_matrix4_invert_affine, Matrix4<f32>, invert_affine);
 
 bench_unop!(_bench_matrix2_transpose, Matrix2<f32>, transpose);
 bench_unop!(_bench_matrix3_transpose, Matrix3<f32>, transpose);
 bench_unop!(_bench_matrix4_transpose, Matrix4<f32>, transpose);
+
+bench_unop!(_bench_matrix4_determinant, Matrix4<f32>, determinant);
+bench_unop!(_bench_matrix4_determinant_lu, Matrix4<f32>, determinant_lu);
@@ -22,7 +22,7 @@
 
 use point::{Point, Point2, Point3};
 use vector::{Vector, Vector2, Vector3};
-use ray::{Ray2};
+use ray::{Ray2, Ray3};
 use intersect::Intersect;
 use num::{zero, one, BaseNum, BaseFloat};
 use std::fmt;
@@ -65,6 +65,10 @@ pub trait Aabb<S: BaseNum, V: Vector<S>, P: Point<S, V>>: Sized {
         Aabb::new(min, max)
     }
 
+    /// Grow this AABB in-place to include the given point.
+    #[inline]
+    fn grow_self(&mut self, p: &P) { *self = self.grow(p); }
+
     /// Add a vector to every point in the AABB, returning a new AABB.
     fn add_v(&self, v: &V) -> Self {
         Aabb::new(self.min().add_v(v), self.max().add_v(v))
@@ -101,6 +105,14 @@ impl<S: BaseNum> Aabb2<S> {
                              p1.y.partial_max(p2.y)),
         }
     }
+
+    /// Test whether this AABB overlaps `other`. Boxes that only touch along
+    /// an edge are considered to overlap.
+    #[inline]
+    pub fn intersects(&self, other: &Aabb2<S>) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
 }
 
 impl<S: BaseNum> Aabb<S, Vector2<S>, Point2<S>> for Aabb2<S> {
@@ -148,6 +160,42 @@ impl<S: BaseNum> Aabb3<S> {
                              p1.z.partial_max(p2.z)),
         }
     }
+
+    /// Test whether this AABB overlaps `other`. Boxes that only touch along
+    /// a face are considered to overlap.
+    #[inline]
+    pub fn intersects(&self, other: &Aabb3<S>) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y &&
+        self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+}
+
+impl<S: BaseFloat> Aabb3<S> {
+    /// Find where `ray` enters and exits this AABB using the slab method,
+    /// returned as the pair of ray parameters `(entry, exit)`. The entry is
+    /// negative if the ray starts inside the box. Returns `None` if the ray
+    /// misses the box or the box is entirely behind the ray.
+    pub fn intersect_ray(&self, ray: &Ray3<S>) -> Option<(S, S)> {
+        let mut tmin: S = Float::neg_infinity();
+        let mut tmax: S = Float::infinity();
+
+        for i in range(0u, 3) {
+            if ray.direction[i] == zero() {
+                // parallel to this slab, so the origin has to lie within it
+                if ray.origin[i] < self.min[i] || ray.origin[i] > self.max[i] {
+                    return None;
+                }
+            } else {
+                let t1 = (self.min[i] - ray.origin[i]) / ray.direction[i];
+                let t2 = (self.max[i] - ray.origin[i]) / ray.direction[i];
+                tmin = tmin.max(t1.min(t2));
+                tmax = tmax.min(t1.max(t2));
+            }
+        }
+
+        if tmax >= tmin && tmax >= zero() { Some((tmin, tmax)) } else { None }
+    }
 }
 
 impl<S: BaseNum> Aabb<S, Vector3<S>, Point3<S>> for Aabb3<S> {
@@ -245,6 +245,16 @@ ApproxEq<S> for Rad<S> {
     fn approx_eq_eps(&self, other: &Rad<S>, epsilon: &S) -> bool {
         self.s.approx_eq_eps(&other.s, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Rad<S>, max_relative: &S) -> bool {
+        self.s.relative_eq(&other.s, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Rad<S>, max_ulps: u32) -> bool {
+        self.s.ulps_eq(&other.s, max_ulps)
+    }
 }
 
 impl<S: BaseFloat>
@@ -253,4 +263,14 @@ ApproxEq<S> for Deg<S> {
     fn approx_eq_eps(&self, other: &Deg<S>, epsilon: &S) -> bool {
         self.s.approx_eq_eps(&other.s, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Deg<S>, max_relative: &S) -> bool {
+        self.s.relative_eq(&other.s, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Deg<S>, max_ulps: u32) -> bool {
+        self.s.ulps_eq(&other.s, max_ulps)
+    }
 }
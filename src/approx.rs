@@ -13,36 +13,86 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::mem;
 use std::num;
 use std::num::Float;
 
+/// Approximate equality for floating point values and the structures built
+/// from them.
 pub trait ApproxEq<T: Float>: Sized {
+    /// The default tolerance used by `approx_eq`.
     fn approx_epsilon(_hack: Option<Self>) -> T {
         num::cast(1.0e-5f64).unwrap()
     }
 
+    /// Compare using the default tolerance given by `approx_epsilon`.
     fn approx_eq(&self, other: &Self) -> bool {
         let eps: T = ApproxEq::approx_epsilon(None::<Self>);
         self.approx_eq_eps(other, &eps)
     }
 
+    /// Compare using an explicit tolerance. Compound types compare each of
+    /// their elements with the same tolerance.
     fn approx_eq_eps(&self, other: &Self, epsilon: &T) -> bool;
+
+    /// Compare relative to the magnitude of the values: two scalars are equal
+    /// if their difference is at most `max_relative` times the larger of
+    /// their absolute values. This scales to large values, but is too strict
+    /// near zero, where `approx_eq_eps` should be used instead.
+    ///
+    /// The default implementation uses `approx_eq_eps` with `max_relative` as
+    /// an absolute tolerance, which is only correct for values around one.
+    /// Types built from floats should compare their elements instead.
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_relative: &T) -> bool {
+        self.approx_eq_eps(other, max_relative)
+    }
+
+    /// Compare by the number of representable floating point values between
+    /// the two values, allowing at most `max_ulps` units in the last place.
+    /// Values of differing sign are only equal if they compare equal.
+    ///
+    /// The default implementation ignores `max_ulps` and uses `approx_eq`.
+    /// Types built from floats should compare their elements instead.
+    #[inline]
+    fn ulps_eq(&self, other: &Self, _max_ulps: u32) -> bool {
+        self.approx_eq(other)
+    }
 }
 
 
 macro_rules! approx_float(
-    ($S:ident) => (
+    ($S:ident, $I:ident) => (
         impl ApproxEq<$S> for $S {
              #[inline]
             fn approx_eq_eps(&self, other: &$S, epsilon: &$S) -> bool {
                  (*self - *other).abs() < *epsilon
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$S, max_relative: &$S) -> bool {
+                if *self == *other { return true; }
+                let largest = self.abs().max(other.abs());
+                (*self - *other).abs() <= largest * *max_relative
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$S, max_ulps: u32) -> bool {
+                if *self == *other { return true; }
+                if self.is_nan() || other.is_nan() { return false; }
+                if self.is_sign_negative() != other.is_sign_negative() { return false; }
+                let a: $I = unsafe { mem::transmute(*self) };
+                let b: $I = unsafe { mem::transmute(*other) };
+                let ulps = if a > b { a - b } else { b - a };
+                // compare as unsigned, as `max_ulps` may not fit in `$I`
+                ulps as u64 <= max_ulps as u64
+            }
         }
     )
 );
 
-approx_float!(f32);
-approx_float!(f64);
+approx_float!(f32, i32);
+approx_float!(f64, i64);
 
 #[macro_export]
 macro_rules! assert_approx_eq_eps(
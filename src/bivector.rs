@@ -0,0 +1,106 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bivectors, representing oriented planes in 3-dimensional space.
+
+use std::fmt;
+use std::num::Float;
+
+use approx::ApproxEq;
+use num::{BaseNum, BaseFloat, zero, one};
+use quaternion::Quaternion;
+use vector::{Vector, EuclideanVector, Vector3};
+
+/// A bivector in 3-dimensional space, as produced by the wedge (outer)
+/// product of two vectors. Each component is the area of the projection of
+/// the oriented plane onto the named coordinate plane.
+///
+/// The components are the same as those of the cross product of the two
+/// vectors, with `yz`, `zx` and `xy` in place of `x`, `y` and `z`, but the
+/// bivector describes the plane rather than its normal.
+#[derive(PartialEq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+pub struct Bivector3<S> { pub yz: S, pub zx: S, pub xy: S }
+
+impl<S: BaseNum> Bivector3<S> {
+    #[inline]
+    pub fn new(yz: S, zx: S, xy: S) -> Bivector3<S> {
+        Bivector3 { yz: yz, zx: zx, xy: xy }
+    }
+
+    /// The wedge product `a ∧ b`.
+    #[inline]
+    pub fn from_wedge(a: &Vector3<S>, b: &Vector3<S>) -> Bivector3<S> {
+        Bivector3::new((a.y * b.z) - (a.z * b.y),
+                       (a.z * b.x) - (a.x * b.z),
+                       (a.x * b.y) - (a.y * b.x))
+    }
+
+    /// The vector normal to the plane of the bivector, which is its dual.
+    #[inline]
+    pub fn to_vector(&self) -> Vector3<S> {
+        Vector3::new(self.yz, self.zx, self.xy)
+    }
+
+    /// Multiply the bivector by a scalar.
+    #[inline]
+    pub fn mul_s(&self, s: S) -> Bivector3<S> {
+        Bivector3::new(self.yz * s, self.zx * s, self.xy * s)
+    }
+}
+
+impl<S: BaseFloat> Bivector3<S> {
+    /// The area of the oriented plane.
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.to_vector().length()
+    }
+
+    /// The exponential map, giving the quaternion that rotates vectors in the
+    /// plane of the bivector by an angle equal to its magnitude. The sense of
+    /// the rotation is from `a` towards `b` for the bivector `a ∧ b`, which
+    /// is the same as `Rotation3::from_axis_angle` about `a × b`.
+    ///
+    /// The zero bivector gives the identity quaternion.
+    pub fn exp(&self) -> Quaternion<S> {
+        let angle = self.magnitude();
+        if angle == zero() { return Quaternion::identity(); }
+        let half = angle / (one::<S>() + one());
+        let (s, c) = half.sin_cos();
+        Quaternion::from_sv(c, self.to_vector().mul_s(s / angle))
+    }
+}
+
+impl<S: BaseFloat> ApproxEq<S> for Bivector3<S> {
+    #[inline]
+    fn approx_eq_eps(&self, other: &Bivector3<S>, epsilon: &S) -> bool {
+        self.to_vector().approx_eq_eps(&other.to_vector(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Bivector3<S>, max_relative: &S) -> bool {
+        self.to_vector().relative_eq(&other.to_vector(), max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Bivector3<S>, max_ulps: u32) -> bool {
+        self.to_vector().ulps_eq(&other.to_vector(), max_ulps)
+    }
+}
+
+impl<S: BaseNum> fmt::Show for Bivector3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} yz, {} zx, {} xy]", self.yz, self.zx, self.xy)
+    }
+}
@@ -44,9 +44,14 @@ pub use matrix::Matrix;
 pub use matrix::{Matrix2, Matrix3, Matrix4};
 pub use matrix::{ToMatrix2, ToMatrix3, ToMatrix4};
 pub use quaternion::{Quaternion, ToQuaternion};
-pub use vector::{Vector, EuclideanVector};
+pub use bivector::Bivector3;
+pub use vector::{Vector, EuclideanVector, Cross};
 pub use vector::{Vector2, Vector3, Vector4};
-pub use vector::dot;
+pub use vector::{dot, midpoint, centroid};
+pub use vector::{barycentric, barycentric3};
+
+pub use interpolate::Lerp;
+pub use interpolate::{catmull_rom, bezier2, bezier3};
 
 pub use angle::{rad, deg};
 pub use angle::{Angle, Rad, Deg};
@@ -57,13 +62,14 @@ pub use angle::{cot, sec, csc};
 pub use angle::{acos, asin, atan, atan2};
 pub use plane::Plane;
 pub use point::{Point, Point2, Point3};
+pub use spherical::Spherical;
 pub use line::{Line, Line2, Line3};
 pub use ray::{Ray, Ray2, Ray3};
 pub use rotation::{Rotation, Rotation2, Rotation3};
 pub use rotation::{Basis3, Basis2};
 pub use rotation::{ToBasis2, ToBasis3};
-pub use transform::{Transform, Transform3};
-pub use transform::{Decomposed, AffineMatrix3};
+pub use transform::{Transform, Transform2, Transform3};
+pub use transform::{Decomposed, AffineMatrix2, AffineMatrix3};
 
 pub use projection::{perspective, frustum, ortho};
 pub use projection::{Projection, PerspectiveFov, Perspective, Ortho};
@@ -75,8 +81,12 @@ pub use intersect::Intersect;
 pub use obb::{Obb2, Obb3};
 pub use sphere::Sphere;
 
+pub use color::{Rgb, Rgba};
+
 pub use approx::ApproxEq;
 pub use num::{PartialOrd, BaseNum, BaseInt, BaseFloat, One, one, Zero, zero};
+pub use util::{clamp, lerp};
+pub use util::{step, smoothstep, smootherstep};
 
 // Modules
 
@@ -85,10 +95,14 @@ mod array;
 mod matrix;
 mod quaternion;
 mod vector;
+mod bivector;
+
+mod interpolate;
 
 mod angle;
 mod plane;
 mod point;
+mod spherical;
 mod line;
 mod ray;
 mod rotation;
@@ -103,5 +117,8 @@ mod intersect;
 mod obb;
 mod sphere;
 
+mod color;
+
 mod approx;
 mod num;
+mod util;
@@ -0,0 +1,210 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Colors with named channels.
+
+use std::fmt;
+use std::num::{cast, Float};
+
+use angle::{Angle, Deg, deg};
+use approx::ApproxEq;
+use interpolate::Lerp;
+use num::{BaseNum, BaseFloat, PartialOrd, zero, one};
+use vector::{Vector, Vector3, Vector4};
+
+/// A color with red, green and blue channels.
+#[derive(PartialEq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[repr(C)]
+pub struct Rgb<S> { pub r: S, pub g: S, pub b: S }
+
+/// A color with red, green, blue and alpha channels.
+#[derive(PartialEq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[repr(C)]
+pub struct Rgba<S> { pub r: S, pub g: S, pub b: S, pub a: S }
+
+impl<S: BaseNum> Rgb<S> {
+    #[inline]
+    pub fn new(r: S, g: S, b: S) -> Rgb<S> {
+        Rgb { r: r, g: g, b: b }
+    }
+
+    /// Construct a color from the components of a vector, in `x`, `y`, `z`
+    /// order.
+    #[inline]
+    pub fn from_vec3(v: &Vector3<S>) -> Rgb<S> {
+        Rgb::new(v.x, v.y, v.z)
+    }
+
+    /// Convert the channels to a vector.
+    #[inline]
+    pub fn to_vec3(&self) -> Vector3<S> {
+        Vector3::new(self.r, self.g, self.b)
+    }
+
+    /// Add an alpha channel to the color.
+    #[inline]
+    pub fn extend(&self, a: S) -> Rgba<S> {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+
+    /// Clamp each channel to the range `[0, 1]`.
+    #[inline]
+    pub fn clamp(&self) -> Rgb<S> {
+        Rgb::from_vec3(&self.to_vec3().clamp(&zero(), &one()))
+    }
+}
+
+impl<S: BaseFloat> Rgb<S> {
+    /// Construct a color from a hue, saturation and value. The hue is wrapped
+    /// to the range `[0, 360)` degrees, and the saturation and value should be
+    /// in the range `[0, 1]`.
+    pub fn from_hsv(h: Deg<S>, s: S, v: S) -> Rgb<S> {
+        let sixty: S = cast(60i).unwrap();
+        let sector = h.normalize().s / sixty;
+        let i = sector.floor();
+        let f = sector - i;
+
+        let p = v * (one::<S>() - s);
+        let q = v * (one::<S>() - s * f);
+        let t = v * (one::<S>() - s * (one::<S>() - f));
+
+        match cast::<S, int>(i).unwrap() % 6 {
+            0 => Rgb::new(v, t, p),
+            1 => Rgb::new(q, v, p),
+            2 => Rgb::new(p, v, t),
+            3 => Rgb::new(p, q, v),
+            4 => Rgb::new(t, p, v),
+            _ => Rgb::new(v, p, q),
+        }
+    }
+
+    /// Convert the color to a hue in the range `[0, 360)` degrees, and a
+    /// saturation and value. Achromatic colors, for which the hue is
+    /// undefined, have a hue of zero.
+    pub fn to_hsv(&self) -> (Deg<S>, S, S) {
+        let max = self.r.partial_max(self.g).partial_max(self.b);
+        let min = self.r.partial_min(self.g).partial_min(self.b);
+        let delta = max - min;
+
+        let s = if max == zero() { zero() } else { delta / max };
+        let h = if delta == zero() {
+            zero()
+        } else {
+            let sixty: S = cast(60i).unwrap();
+            let sector = if max == self.r {
+                (self.g - self.b) / delta
+            } else if max == self.g {
+                (self.b - self.r) / delta + cast(2i).unwrap()
+            } else {
+                (self.r - self.g) / delta + cast(4i).unwrap()
+            };
+            sector * sixty
+        };
+
+        (deg(h).normalize(), s, max)
+    }
+}
+
+impl<S: BaseNum> Rgba<S> {
+    #[inline]
+    pub fn new(r: S, g: S, b: S, a: S) -> Rgba<S> {
+        Rgba { r: r, g: g, b: b, a: a }
+    }
+
+    /// Construct a color from the components of a vector, in `x`, `y`, `z`,
+    /// `w` order.
+    #[inline]
+    pub fn from_vec4(v: &Vector4<S>) -> Rgba<S> {
+        Rgba::new(v.x, v.y, v.z, v.w)
+    }
+
+    /// Convert the channels to a vector.
+    #[inline]
+    pub fn to_vec4(&self) -> Vector4<S> {
+        Vector4::new(self.r, self.g, self.b, self.a)
+    }
+
+    /// Discard the alpha channel.
+    #[inline]
+    pub fn truncate(&self) -> Rgb<S> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// Clamp each channel to the range `[0, 1]`.
+    #[inline]
+    pub fn clamp(&self) -> Rgba<S> {
+        Rgba::from_vec4(&self.to_vec4().clamp(&zero(), &one()))
+    }
+}
+
+impl<S: BaseNum> Lerp<S> for Rgb<S> {
+    #[inline]
+    fn lerp(&self, other: &Rgb<S>, amount: S) -> Rgb<S> {
+        Rgb::from_vec3(&self.to_vec3().lerp(&other.to_vec3(), amount))
+    }
+}
+
+impl<S: BaseNum> Lerp<S> for Rgba<S> {
+    #[inline]
+    fn lerp(&self, other: &Rgba<S>, amount: S) -> Rgba<S> {
+        Rgba::from_vec4(&self.to_vec4().lerp(&other.to_vec4(), amount))
+    }
+}
+
+impl<S: BaseFloat> ApproxEq<S> for Rgb<S> {
+    #[inline]
+    fn approx_eq_eps(&self, other: &Rgb<S>, epsilon: &S) -> bool {
+        self.to_vec3().approx_eq_eps(&other.to_vec3(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Rgb<S>, max_relative: &S) -> bool {
+        self.to_vec3().relative_eq(&other.to_vec3(), max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Rgb<S>, max_ulps: u32) -> bool {
+        self.to_vec3().ulps_eq(&other.to_vec3(), max_ulps)
+    }
+}
+
+impl<S: BaseFloat> ApproxEq<S> for Rgba<S> {
+    #[inline]
+    fn approx_eq_eps(&self, other: &Rgba<S>, epsilon: &S) -> bool {
+        self.to_vec4().approx_eq_eps(&other.to_vec4(), epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Rgba<S>, max_relative: &S) -> bool {
+        self.to_vec4().relative_eq(&other.to_vec4(), max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Rgba<S>, max_ulps: u32) -> bool {
+        self.to_vec4().ulps_eq(&other.to_vec4(), max_ulps)
+    }
+}
+
+impl<S: BaseNum> fmt::Show for Rgb<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+}
+
+impl<S: BaseNum> fmt::Show for Rgba<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+}
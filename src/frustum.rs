@@ -17,10 +17,11 @@
 
 use array::Array2;
 use matrix::Matrix4;
-use num::BaseFloat;
+use num::{BaseFloat, zero};
 use plane::Plane;
 use point::Point3;
-use vector::{Vector, EuclideanVector};
+use sphere::Sphere;
+use vector::{Vector, Vector4};
 
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct Frustum<S> {
@@ -48,14 +49,36 @@ Frustum<S> {
         }
     }
 
-    /// Extracts frustum planes from a projection matrix
+    /// Extracts frustum planes from a projection matrix, or a combined
+    /// view-projection matrix, using the Gribb/Hartmann method. The normals of
+    /// the resulting planes point into the frustum.
     pub fn from_matrix4(mat: Matrix4<S>) -> Frustum<S> {
-        Frustum::new(Plane::from_vector4(mat.row(3).add_v(&mat.row(0)).normalize()),
-                     Plane::from_vector4(mat.row(3).sub_v(&mat.row(0)).normalize()),
-                     Plane::from_vector4(mat.row(3).add_v(&mat.row(1)).normalize()),
-                     Plane::from_vector4(mat.row(3).sub_v(&mat.row(1)).normalize()),
-                     Plane::from_vector4(mat.row(3).add_v(&mat.row(2)).normalize()),
-                     Plane::from_vector4(mat.row(3).sub_v(&mat.row(2)).normalize()))
+        // each row sum gives the `A*x + B*y + C*z + D >= 0` form of a plane
+        let plane = |v: Vector4<S>| Plane::from_abcd(v.x, v.y, v.z, -v.w).normalize();
+        Frustum::new(plane(mat.row(3).add_v(&mat.row(0))),
+                     plane(mat.row(3).sub_v(&mat.row(0))),
+                     plane(mat.row(3).add_v(&mat.row(1))),
+                     plane(mat.row(3).sub_v(&mat.row(1))),
+                     plane(mat.row(3).add_v(&mat.row(2))),
+                     plane(mat.row(3).sub_v(&mat.row(2))))
+    }
+
+    /// Test whether the point `p` is inside, or on the boundary of, the
+    /// frustum.
+    pub fn contains(&self, p: &Point3<S>) -> bool {
+        self.planes().iter().all(|plane| plane.distance(p) >= zero())
+    }
+
+    /// Test whether any part of the sphere `s` is inside the frustum. This is
+    /// conservative: spheres near the corners of the frustum may be reported
+    /// as intersecting even though they are just outside.
+    pub fn intersects_sphere(&self, s: &Sphere<S>) -> bool {
+        self.planes().iter().all(|plane| plane.distance(&s.center) >= -s.radius)
+    }
+
+    #[inline]
+    fn planes(&self) -> [Plane<S>; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
     }
 }
 
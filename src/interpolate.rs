@@ -0,0 +1,108 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpolation between values.
+
+use std::num::cast;
+
+use matrix::{Matrix, Matrix2, Matrix3, Matrix4};
+use num::{BaseNum, BaseFloat};
+use vector::{Vector, Vector2, Vector3, Vector4};
+
+/// Types that can be linearly interpolated.
+pub trait Lerp<S> {
+    /// Returns the result of linearly interpolating towards `other` by the
+    /// specified amount, where an amount of `0` gives `self` and an amount of
+    /// `1` gives `other`.
+    fn lerp(&self, other: &Self, amount: S) -> Self;
+}
+
+macro_rules! impl_lerp_scalar(
+    ($S:ty) => (
+        impl Lerp<$S> for $S {
+            #[inline]
+            fn lerp(&self, other: &$S, amount: $S) -> $S {
+                *self + (*other - *self) * amount
+            }
+        }
+    )
+);
+
+impl_lerp_scalar!(f32);
+impl_lerp_scalar!(f64);
+
+macro_rules! impl_lerp_vector(
+    ($Self_:ident) => (
+        impl<S: BaseNum> Lerp<S> for $Self_<S> {
+            #[inline]
+            fn lerp(&self, other: &$Self_<S>, amount: S) -> $Self_<S> {
+                self.add_v(&other.sub_v(self).mul_s(amount))
+            }
+        }
+    )
+);
+
+impl_lerp_vector!(Vector2);
+impl_lerp_vector!(Vector3);
+impl_lerp_vector!(Vector4);
+
+macro_rules! impl_lerp_matrix(
+    ($Self_:ident) => (
+        impl<S: BaseFloat + 'static> Lerp<S> for $Self_<S> {
+            #[inline]
+            fn lerp(&self, other: &$Self_<S>, amount: S) -> $Self_<S> {
+                self.add_m(&other.sub_m(self).mul_s(amount))
+            }
+        }
+    )
+);
+
+impl_lerp_matrix!(Matrix2);
+impl_lerp_matrix!(Matrix3);
+impl_lerp_matrix!(Matrix4);
+
+/// Evaluate the uniform Catmull-Rom spline segment between `p1` and `p2` at
+/// `t`, using `p0` and `p3` to determine the tangents at either end. The curve
+/// passes through `p1` at `t = 0` and through `p2` at `t = 1`.
+pub fn catmull_rom<S: BaseFloat, V: Vector<S>>(p0: &V, p1: &V, p2: &V, p3: &V, t: S) -> V {
+    let two: S = cast(2i).unwrap();
+    let three: S = cast(3i).unwrap();
+    let four: S = cast(4i).unwrap();
+    let five: S = cast(5i).unwrap();
+
+    let a = p1.mul_s(two);
+    let b = p2.sub_v(p0);
+    let c = p0.mul_s(two).sub_v(&p1.mul_s(five)).add_v(&p2.mul_s(four)).sub_v(p3);
+    let d = p1.sub_v(p2).mul_s(three).sub_v(p0).add_v(p3);
+
+    a.add_v(&b.add_v(&c.add_v(&d.mul_s(t)).mul_s(t)).mul_s(t)).div_s(two)
+}
+
+/// Evaluate the quadratic Bézier curve with control points `p0`, `p1` and `p2`
+/// at `t`, using de Casteljau's algorithm.
+pub fn bezier2<S: Copy, V: Lerp<S>>(p0: &V, p1: &V, p2: &V, t: S) -> V {
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    a.lerp(&b, t)
+}
+
+/// Evaluate the cubic Bézier curve with control points `p0`, `p1`, `p2` and
+/// `p3` at `t`, using de Casteljau's algorithm.
+pub fn bezier3<S: Copy, V: Lerp<S>>(p0: &V, p1: &V, p2: &V, p3: &V, t: S) -> V {
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    let c = p2.lerp(p3, t);
+    bezier2(&a, &b, &c, t)
+}
@@ -16,29 +16,35 @@
 //! Column major, square matrix types and traits.
 
 use std::fmt;
+use std::iter::FromIterator;
 use std::mem;
-use std::num::cast;
+use std::slice;
+use std::num::{cast, Float, NumCast};
 use std::ops::*;
 
-use angle::{Rad, sin, cos, sin_cos};
+use angle::{Angle, Rad, atan2, rad, sin, cos, sin_cos};
 use approx::ApproxEq;
 use array::{Array1, Array2, FixedArray};
 use num::{BaseFloat, BaseNum, Zero, zero, One, one};
-use point::{Point, Point3};
+use point::{Point, Point2, Point3};
 use quaternion::{Quaternion, ToQuaternion};
 use vector::{Vector, EuclideanVector};
 use vector::{Vector2, Vector3, Vector4};
+use vector::perpendicular;
 
 /// A 2 x 2, column major matrix
-#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable, Rand)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable, Rand)]
+#[repr(C)]
 pub struct Matrix2<S> { pub x: Vector2<S>, pub y: Vector2<S> }
 
 /// A 3 x 3, column major matrix
-#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable, Rand)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable, Rand)]
+#[repr(C)]
 pub struct Matrix3<S> { pub x: Vector3<S>, pub y: Vector3<S>, pub z: Vector3<S> }
 
 /// A 4 x 4, column major matrix
-#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable, Rand)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable, Rand)]
+#[repr(C)]
 pub struct Matrix4<S> { pub x: Vector4<S>, pub y: Vector4<S>, pub z: Vector4<S>, pub w: Vector4<S> }
 
 
@@ -65,6 +71,26 @@ impl<S: BaseNum> Matrix2<S> {
                      zero(), value.clone())
     }
 
+    /// Create a diagonal matrix, with the components of `value` on the
+    /// diagonal and zeros elsewhere.
+    #[inline]
+    pub fn from_diagonal(value: &Vector2<S>) -> Matrix2<S> {
+        Matrix2::new(value.x, zero(),
+                     zero(),  value.y)
+    }
+
+    /// Create a matrix from a nested array of columns.
+    #[inline]
+    pub fn from_array(a: [[S; 2]; 2]) -> Matrix2<S> {
+        *FixedArray::from_fixed_ref(&a)
+    }
+
+    /// Copy the matrix into a nested array of columns.
+    #[inline]
+    pub fn to_array(&self) -> [[S; 2]; 2] {
+        *self.as_fixed()
+    }
+
     /// Create a zero matrix (all zeros).
     #[inline]
     pub fn zero() -> Matrix2<S> {
@@ -76,6 +102,13 @@ impl<S: BaseNum> Matrix2<S> {
     pub fn identity() -> Matrix2<S> {
         Matrix2::from_value(one())
     }
+
+    /// Create a matrix from the outer product of two vectors, such that the
+    /// element at column `j`, row `i` is `a[i] * b[j]`.
+    #[inline]
+    pub fn from_outer(a: &Vector2<S>, b: &Vector2<S>) -> Matrix2<S> {
+        Matrix2::from_cols(a.mul_s(b.x), a.mul_s(b.y))
+    }
 }
 
 impl<S: BaseFloat + 'static> Matrix2<S> {
@@ -122,6 +155,27 @@ impl<S: BaseNum> Matrix3<S> {
                      zero(), zero(), value.clone())
     }
 
+    /// Create a diagonal matrix, with the components of `value` on the
+    /// diagonal and zeros elsewhere.
+    #[inline]
+    pub fn from_diagonal(value: &Vector3<S>) -> Matrix3<S> {
+        Matrix3::new(value.x, zero(),  zero(),
+                     zero(),  value.y, zero(),
+                     zero(),  zero(),  value.z)
+    }
+
+    /// Create a matrix from a nested array of columns.
+    #[inline]
+    pub fn from_array(a: [[S; 3]; 3]) -> Matrix3<S> {
+        *FixedArray::from_fixed_ref(&a)
+    }
+
+    /// Copy the matrix into a nested array of columns.
+    #[inline]
+    pub fn to_array(&self) -> [[S; 3]; 3] {
+        *self.as_fixed()
+    }
+
     /// Create a zero matrix (all zeros).
     #[inline]
     pub fn zero() -> Matrix3<S> {
@@ -133,6 +187,60 @@ impl<S: BaseNum> Matrix3<S> {
     pub fn identity() -> Matrix3<S> {
         Matrix3::from_value(one())
     }
+
+    /// Create a matrix that scales uniformly along every axis.
+    #[inline]
+    pub fn from_scale(value: S) -> Matrix3<S> {
+        Matrix3::from_nonuniform_scale(value, value, value)
+    }
+
+    /// Create a matrix that scales by a different amount along each axis.
+    #[inline]
+    pub fn from_nonuniform_scale(x: S, y: S, z: S) -> Matrix3<S> {
+        Matrix3::new(x,      zero(), zero(),
+                     zero(), y,      zero(),
+                     zero(), zero(), z)
+    }
+
+    /// Create a shear matrix. Each parameter is named after the axis it
+    /// displaces, followed by the axis that it is proportional to, so `xy`
+    /// is stored at row `x`, column `y`, and moves a vector along `x` by `xy`
+    /// times its `y` component:
+    ///
+    /// ```text
+    /// x' = x + xy * y + xz * z
+    /// y' = y + yx * x + yz * z
+    /// z' = z + zx * x + zy * y
+    /// ```
+    #[inline]
+    pub fn from_shear(xy: S, xz: S, yx: S, yz: S, zx: S, zy: S) -> Matrix3<S> {
+        Matrix3::new(one(), yx,    zx,
+                     xy,    one(), zy,
+                     xz,    yz,    one())
+    }
+
+    /// Create a homogeneous 2-dimensional shear matrix, moving points along
+    /// `x` by `shx` times their `y` coordinate, and along `y` by `shy` times
+    /// their `x` coordinate. This is `from_shear(shx, 0, shy, 0, 0, 0)`.
+    #[inline]
+    pub fn from_shear_2d(shx: S, shy: S) -> Matrix3<S> {
+        Matrix3::from_shear(shx, zero(), shy, zero(), zero(), zero())
+    }
+
+    /// Create a matrix from the outer product of two vectors, such that the
+    /// element at column `j`, row `i` is `a[i] * b[j]`.
+    #[inline]
+    pub fn from_outer(a: &Vector3<S>, b: &Vector3<S>) -> Matrix3<S> {
+        Matrix3::from_cols(a.mul_s(b.x), a.mul_s(b.y), a.mul_s(b.z))
+    }
+
+    /// Create a 2-dimensional matrix from the top-left corner of this matrix,
+    /// discarding the last row and column.
+    #[inline]
+    pub fn truncate(&self) -> Matrix2<S> {
+        Matrix2::from_cols(self.x.truncate(),
+                           self.y.truncate())
+    }
 }
 
 impl<S: BaseFloat + 'static>
@@ -176,6 +284,10 @@ Matrix3<S> {
 
     /// Create a matrix from a set of euler angles.
     ///
+    /// The rotations are applied to a vector about the fixed `x`, then `y`,
+    /// then `z` axes. This is equal to `from_angle_z(z) * from_angle_y(y) *
+    /// from_angle_x(x)`, or intrinsic rotations in `z`, `y`, `x` order.
+    ///
     /// # Parameters
     ///
     /// - `x`: the angular rotation around the `x` axis (pitch).
@@ -192,8 +304,34 @@ Matrix3<S> {
                       sx * sz + cx * sy * cz, -sx * cz + cx * sy * sz, cx * cy)
     }
 
-    /// Create a matrix from a rotation around an arbitrary axis
+    /// Extract the euler angles `(x, y, z)` of a rotation matrix, in the
+    /// convention used by `from_euler`, such that `from_euler(x, y, z)`
+    /// recreates the matrix. `y` is in the range `[-pi/2, pi/2]`, and `x` and
+    /// `z` are in the range `[-pi, pi]`.
+    ///
+    /// When `y` is at either end of its range, rotations around `x` and `z`
+    /// act around the same axis (gimbal lock). This is only detected when the
+    /// cosine of `y` is within a few multiples of the machine epsilon of zero,
+    /// in which case the whole rotation is attributed to `x`, and `z` is zero.
+    pub fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>) {
+        let sy = -self.x.z;
+        let cy = self.x.x.hypot(self.x.y);
+        let y = atan2(sy, cy);
+
+        let eps: S = Float::epsilon();
+        if cy <= eps * cast(16i).unwrap() {
+            (atan2(-self.z.y, self.y.y), y, rad(zero()))
+        } else {
+            (atan2(self.y.z, self.z.z), y, atan2(self.x.y, self.x.x))
+        }
+    }
+
+    /// Create a matrix from a rotation around an arbitrary axis, using the
+    /// [Rodrigues rotation formula](http://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula).
+    ///
+    /// The axis does not need to be normalized.
     pub fn from_axis_angle(axis: &Vector3<S>, angle: Rad<S>) -> Matrix3<S> {
+        let axis = axis.normalize();
         let (s, c) = sin_cos(angle);
         let _1subc = one::<S>() - c;
 
@@ -210,11 +348,101 @@ Matrix3<S> {
                      _1subc * axis.z * axis.z + c)
     }
 
-    /// Create a matrix from a non-uniform scale
-    pub fn from_diagonal(value: &Vector3<S>) -> Matrix3<S> {
-        Matrix3::new(value.x, zero(),  zero(),
-                     zero(),  value.y, zero(),
-                     zero(),  zero(),  value.z)
+    /// Create the shortest-arc rotation that maps the direction of `from`
+    /// onto the direction of `to`. Neither vector needs to be normalized.
+    ///
+    /// If the vectors point in opposite directions, to within a few ulps,
+    /// there is no unique shortest arc, so this returns a half turn around an
+    /// arbitrary axis perpendicular to `from`.
+    pub fn from_rotation_between(from: &Vector3<S>, to: &Vector3<S>) -> Matrix3<S> {
+        let a = from.normalize();
+        let b = to.normalize();
+        let c = a.dot(&b);
+        let k = one::<S>() + c;
+
+        // only treat the vectors as opposite when `k` is lost to rounding, as
+        // nearly opposite vectors still have a well-defined shortest arc
+        let epsilon: S = Float::epsilon();
+        if k <= epsilon * cast(4i).unwrap() {
+            return Matrix3::from_axis_angle(&perpendicular(&a), Angle::turn_div_2());
+        }
+
+        // Rodrigues' formula, with the sine of the angle folded into the
+        // length of the axis: `c * I + [v]x + v * v^T / (1 + c)`
+        let v = a.cross(&b);
+        Matrix3::new(c + v.x * v.x / k,
+                     v.z + v.x * v.y / k,
+                     -v.y + v.x * v.z / k,
+
+                     -v.z + v.x * v.y / k,
+                     c + v.y * v.y / k,
+                     v.x + v.y * v.z / k,
+
+                     v.y + v.x * v.z / k,
+                     -v.x + v.y * v.z / k,
+                     c + v.z * v.z / k)
+    }
+
+    /// Transform a 2-dimensional point by this matrix, treating it as a
+    /// homogeneous vector with a `z` of one.
+    #[inline]
+    pub fn transform_point(&self, p: &Point2<S>) -> Point2<S> {
+        Point2::from_homogeneous(&self.mul_v(&p.to_homogeneous()))
+    }
+
+    /// Find the eigenvalues and eigenvectors of this matrix using the Jacobi
+    /// eigenvalue algorithm. The eigenvectors are returned as the columns of
+    /// the matrix, in the same order as their eigenvalues.
+    ///
+    /// The matrix must be symmetric.
+    pub fn symmetric_eigen(&self) -> (Vector3<S>, Matrix3<S>) {
+        assert!(self.is_symmetric(), "Attempted to find the eigenvalues of a non-symmetric matrix");
+
+        let one: S = one();
+        let two: S = cast(2i).unwrap();
+        let mut a = *self;
+        let mut v = Matrix3::identity();
+
+        // the rotations preserve the Frobenius norm, so the stopping
+        // tolerance can be scaled by it once
+        let epsilon: S = ApproxEq::approx_epsilon(None::<S>);
+        let tolerance = epsilon * self.as_slice().iter().fold(zero::<S>(), |acc, &x| acc + x * x).sqrt();
+
+        for _ in range(0u, 32) {
+            // pick the largest off-diagonal element to eliminate
+            let (p, q) = if a[1][0].abs() >= a[2][0].abs() && a[1][0].abs() >= a[2][1].abs() { (0, 1) }
+                         else if a[2][0].abs() >= a[2][1].abs() { (0, 2) }
+                         else { (1, 2) };
+            if a[q][p].abs() <= tolerance { break; }
+
+            // the rotation in the `p`-`q` plane that zeroes `a[q][p]`
+            let theta = (a[q][q] - a[p][p]) / (two * a[q][p]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+            let c = one / (t * t + one).sqrt();
+            let s = t * c;
+
+            let mut j = Matrix3::identity();
+            j[p][p] = c;
+            j[q][q] = c;
+            j[q][p] = s;
+            j[p][q] = -s;
+
+            a = j.transpose().mul_m(&a).mul_m(&j);
+            v = v.mul_m(&j);
+        }
+
+        (a.diagonal(), v)
+    }
+
+    /// Decompose this matrix into an orthogonal matrix `Q` and an upper
+    /// triangular matrix `R`, such that `q.mul_m(&r)` is equal to `self`.
+    ///
+    /// `Q` is found by orthonormalizing the columns of the matrix, so they
+    /// must be linearly independent.
+    pub fn qr(&self) -> (Matrix3<S>, Matrix3<S>) {
+        let q = self.orthonormalize();
+        let r = q.transpose().mul_m(self);
+        (q, r)
     }
 }
 
@@ -247,6 +475,28 @@ impl<S: BaseNum> Matrix4<S> {
                             zero(),        zero(),        zero(), value.clone())
     }
 
+    /// Create a diagonal matrix, with the components of `value` on the
+    /// diagonal and zeros elsewhere.
+    #[inline]
+    pub fn from_diagonal(value: &Vector4<S>) -> Matrix4<S> {
+        Matrix4::new(value.x, zero(),  zero(),  zero(),
+                     zero(),  value.y, zero(),  zero(),
+                     zero(),  zero(),  value.z, zero(),
+                     zero(),  zero(),  zero(),  value.w)
+    }
+
+    /// Create a matrix from a nested array of columns.
+    #[inline]
+    pub fn from_array(a: [[S; 4]; 4]) -> Matrix4<S> {
+        *FixedArray::from_fixed_ref(&a)
+    }
+
+    /// Copy the matrix into a nested array of columns.
+    #[inline]
+    pub fn to_array(&self) -> [[S; 4]; 4] {
+        *self.as_fixed()
+    }
+
     /// Create a zero matrix (all zeros).
     #[inline]
     pub fn zero() -> Matrix4<S> {
@@ -259,6 +509,39 @@ impl<S: BaseNum> Matrix4<S> {
         Matrix4::from_value(one())
     }
 
+    /// Create a homogeneous transformation matrix that scales uniformly along
+    /// every axis.
+    #[inline]
+    pub fn from_scale(value: S) -> Matrix4<S> {
+        Matrix4::from_nonuniform_scale(value, value, value)
+    }
+
+    /// Create a homogeneous transformation matrix that scales by a different
+    /// amount along each axis.
+    #[inline]
+    pub fn from_nonuniform_scale(x: S, y: S, z: S) -> Matrix4<S> {
+        Matrix4::new(x,      zero(), zero(), zero(),
+                     zero(), y,      zero(), zero(),
+                     zero(), zero(), z,      zero(),
+                     zero(), zero(), zero(), one())
+    }
+
+    /// Create a matrix from the outer product of two vectors, such that the
+    /// element at column `j`, row `i` is `a[i] * b[j]`.
+    #[inline]
+    pub fn from_outer(a: &Vector4<S>, b: &Vector4<S>) -> Matrix4<S> {
+        Matrix4::from_cols(a.mul_s(b.x), a.mul_s(b.y), a.mul_s(b.z), a.mul_s(b.w))
+    }
+
+    /// Create a 3-dimensional matrix from the top-left corner of this matrix,
+    /// discarding the last row and column.
+    #[inline]
+    pub fn truncate(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.x.truncate(),
+                           self.y.truncate(),
+                           self.z.truncate())
+    }
+
     /// Create a translation matrix from a Vector3
     #[inline]
     pub fn from_translation(v: &Vector3<S>) -> Matrix4<S> {
@@ -267,21 +550,128 @@ impl<S: BaseNum> Matrix4<S> {
                      zero(), zero(), one(),  zero(),
                      v.x,    v.y,    v.z,    one())
     }
+
+    /// Create a homogeneous transformation matrix that applies the linear
+    /// transformation `rot`, followed by a translation by `disp`.
+    #[inline]
+    pub fn from_rotation_translation(rot: &Matrix3<S>, disp: &Vector3<S>) -> Matrix4<S> {
+        Matrix4::from_cols(rot.x.extend(zero()),
+                           rot.y.extend(zero()),
+                           rot.z.extend(zero()),
+                           disp.extend(one()))
+    }
 }
 
-impl<S: BaseFloat>
+impl<S: BaseFloat + 'static>
 Matrix4<S> {
-    /// Create a transformation matrix that will cause a vector to point at
-    /// `dir`, using `up` for orientation.
+    /// Create a right-handed view matrix looking from `eye` towards `center`,
+    /// using `up` for orientation.
+    ///
+    /// The rotation is the basis built by `Matrix3::look_at`, with the viewer
+    /// facing down the negative `z` axis. It is followed by a translation that
+    /// moves `eye` to the origin.
     pub fn look_at(eye: &Point3<S>, center: &Point3<S>, up: &Vector3<S>) -> Matrix4<S> {
-        let f = center.sub_p(eye).normalize();
-        let s = f.cross(up).normalize();
-        let u = s.cross(&f);
+        let rot = Matrix3::look_at(&eye.sub_p(center), up);
+        let disp = rot.mul_v(&eye.to_vec()).neg();
 
-        Matrix4::new( s.x.clone(),  u.x.clone(), -f.x.clone(), zero(),
-                      s.y.clone(),  u.y.clone(), -f.y.clone(), zero(),
-                      s.z.clone(),  u.z.clone(), -f.z.clone(), zero(),
-                     -eye.dot(&s), -eye.dot(&u),  eye.dot(&f),  one())
+        let mut mat = rot.to_matrix4();
+        mat.w = disp.extend(one());
+        mat
+    }
+
+    /// Transform a 3-dimensional point by this matrix, treating it as a
+    /// homogeneous vector with a `w` of one.
+    #[inline]
+    pub fn transform_point(&self, p: &Point3<S>) -> Point3<S> {
+        Point3::from_homogeneous(&self.mul_v(&p.to_homogeneous()))
+    }
+
+    /// Decompose this matrix into an orthogonal matrix `Q` and an upper
+    /// triangular matrix `R`, such that `q.mul_m(&r)` is equal to `self`.
+    ///
+    /// `Q` is found by orthonormalizing the columns of the matrix, so they
+    /// must be linearly independent.
+    pub fn qr(&self) -> (Matrix4<S>, Matrix4<S>) {
+        let q = self.orthonormalize();
+        let r = q.transpose().mul_m(self);
+        (q, r)
+    }
+
+    /// Invert an affine transformation matrix, whose bottom row is
+    /// `[0, 0, 0, 1]`. This only inverts the upper 3 x 3 linear part, and
+    /// finds the inverse translation from it, so it is cheaper and more
+    /// accurate than the general `invert`. The bottom row is not checked, so
+    /// this gives incorrect results for projective matrices.
+    ///
+    /// Returns `None` if the linear part is not invertible.
+    pub fn invert_affine(&self) -> Option<Matrix4<S>> {
+        self.truncate().invert().map(|linear| {
+            let disp = linear.mul_v(&self.w.truncate());
+            let mut m = linear.to_matrix4();
+            m.w = (-disp).extend(one());
+            m
+        })
+    }
+
+    /// The matrix used to transform surface normals, which is the inverse
+    /// transpose of the upper 3 x 3 linear part. Normals transformed by it
+    /// stay perpendicular to their surfaces under non-uniform scaling and
+    /// shear.
+    ///
+    /// Returns `None` if the linear part is not invertible.
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Matrix3<S>> {
+        self.truncate().invert().map(|m| m.transpose())
+    }
+
+    /// Decompose an affine transformation matrix into its scale, rotation
+    /// and translation, such that the matrix scales by the first, then
+    /// rotates by the second, and then translates by the third.
+    ///
+    /// The scale is taken from the lengths of the first three columns. If the
+    /// matrix mirrors space (has a negative determinant), the `x` scale is
+    /// negated so that the remaining basis is a proper rotation. Shear and
+    /// projection are not representable, and the matrix must not have a zero
+    /// scale on any axis.
+    pub fn decompose(&self) -> (Vector3<S>, Quaternion<S>, Vector3<S>) {
+        let m = self.truncate();
+        let mut scale = Vector3::new(m.x.length(), m.y.length(), m.z.length());
+        if m.determinant() < zero() { scale.x = -scale.x; }
+
+        let rot = Matrix3::from_cols(m.x.div_s(scale.x),
+                                     m.y.div_s(scale.y),
+                                     m.z.div_s(scale.z));
+        (scale, rot.to_quaternion(), self.w.truncate())
+    }
+
+    /// Compute the determinant using an LU decomposition with partial
+    /// pivoting. This takes fewer operations than the cofactor expansion
+    /// used by `determinant`, and loses less precision on ill-conditioned
+    /// matrices.
+    pub fn determinant_lu(&self) -> S {
+        // The determinant of the transpose is the same, so the columns can
+        // be eliminated as if they were rows.
+        let mut a = self.to_array();
+        let mut det: S = one();
+        for k in range(0u, 4) {
+            let mut p = k;
+            for i in range(k + 1, 4) {
+                if a[i][k].abs() > a[p][k].abs() { p = i; }
+            }
+            if a[p][k] == zero() { return zero(); }
+            if p != k {
+                let tmp = a[p]; a[p] = a[k]; a[k] = tmp;
+                det = -det;
+            }
+            det = det * a[k][k];
+            for i in range(k + 1, 4) {
+                let f = a[i][k] / a[k][k];
+                for j in range(k + 1, 4) {
+                    a[i][j] = a[i][j] - f * a[k][j];
+                }
+            }
+        }
+        det
     }
 }
 
@@ -304,11 +694,18 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     fn sub_m(&self, m: &Self) -> Self;
 
     /// Multiplay a vector by this matrix, returning a new vector.
+    ///
+    /// The vector is treated as a column, so this computes `self * v`.
     fn mul_v(&self, v: &V) -> V;
 
     /// Multiply this matrix by another matrix, returning the new matrix.
     fn mul_m(&self, m: &Self) -> Self;
 
+    /// Multiply the transpose of this matrix by another matrix, returning
+    /// the new matrix. This is equivalent to `self.transpose().mul_m(m)`,
+    /// but avoids building the transposed matrix.
+    fn transpose_mul(&self, m: &Self) -> Self;
+
     /// Negate this matrix in-place (multiply by scalar -1).
     fn neg_self(&mut self);
 
@@ -328,6 +725,15 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     #[inline]
     fn mul_self_m(&mut self, m: &Self) { *self = self.mul_m(m); }
 
+    /// Raise this matrix to the power `n` by repeated squaring. `m.powi(0)` is
+    /// the identity matrix.
+    fn powi(&self, n: uint) -> Self {
+        if n == 0 { return one(); }
+        let half = self.powi(n / 2);
+        let square = half.mul_m(&half);
+        if n % 2 == 1 { square.mul_m(self) } else { square }
+    }
+
     /// Transpose this matrix, returning a new matrix.
     fn transpose(&self) -> Self;
     /// Transpose this matrix in-place.
@@ -342,20 +748,53 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     #[inline]
     fn trace(&self) -> S { self.diagonal().comp_add() }
 
+    /// Return the deviatoric part of this matrix. That is, the matrix with its
+    /// isotropic part, `trace / n` times the identity, removed. The result
+    /// always has a trace of zero.
+    #[inline]
+    fn deviator(&self) -> Self {
+        let id: Self = one();
+        self.sub_m(&id.mul_s(self.trace() / id.trace()))
+    }
+
+    /// Return the adjugate of this matrix. That is, the transpose of its
+    /// cofactor matrix. `m.mul_m(&m.adjugate())` is the identity matrix scaled
+    /// by the determinant of `m`.
+    fn adjugate(&self) -> Self;
+
     /// Invert this matrix, returning a new matrix. `m.mul_m(m.invert())` is
     /// the identity matrix. Returns `None` if this matrix is not invertible
     /// (has a determinant of zero).
+    ///
+    /// The tolerance used to decide whether the determinant is zero is scaled
+    /// by the product of the lengths of the columns, which bounds the
+    /// magnitude of the determinant. For `Matrix4` the last column is left
+    /// out, so that large translations do not affect the tolerance.
     fn invert(&self) -> Option<Self>;
 
+    /// Invert this matrix, returning a new matrix, or `None` if the magnitude
+    /// of the determinant is not above `epsilon`.
+    fn invert_with_epsilon(&self, epsilon: S) -> Option<Self>;
+
     /// Invert this matrix in-place.
     #[inline]
     fn invert_self(&mut self) {
         *self = self.invert().expect("Attempted to invert a matrix with zero determinant.");
     }
 
-    /// Test if this matrix is invertible.
+    /// Orthonormalize the columns of this matrix using the modified
+    /// Gram-Schmidt process, returning a new matrix. This removes the drift
+    /// that builds up in rotation matrices after many multiplications.
+    fn orthonormalize(&self) -> Self;
+
+    /// Orthonormalize the columns of this matrix in-place.
+    #[inline]
+    fn orthonormalize_self(&mut self) { *self = self.orthonormalize(); }
+
+    /// Test if this matrix is invertible, using the same tolerance as
+    /// `invert`.
     #[inline]
-    fn is_invertible(&self) -> bool { !self.determinant().approx_eq(&zero()) }
+    fn is_invertible(&self) -> bool { self.invert().is_some() }
 
     /// Test if this matrix is the identity matrix. That is, it is diagonal
     /// and every element in the diagonal is one.
@@ -369,6 +808,44 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
     fn is_symmetric(&self) -> bool;
+
+    /// Test if this matrix has a rotational component. That is, the proper
+    /// rotation `R` of its polar decomposition `M = R * P` is not the
+    /// identity. `P` is symmetric, and holds any mirroring of the matrix as a
+    /// single negative eigenvalue, so the matrix is rotated unless it is
+    /// symmetric with at most one negative eigenvalue.
+    ///
+    /// Scales along any set of orthogonal axes are not rotations, including
+    /// a negative scale along one of them, which mirrors space. A negative
+    /// scale along two axes is a half turn, so it is rotated. Shears always
+    /// have a rotational component.
+    ///
+    /// `Matrix4` is treated as a homogeneous transformation, so only its
+    /// upper-left 3 x 3 part is checked, and translation is ignored.
+    fn is_rotated(&self) -> bool;
+
+    /// Test if this matrix is orthogonal. That is, its transpose is equal to
+    /// its inverse.
+    #[inline]
+    fn is_orthogonal(&self) -> bool { self.mul_m(&self.transpose()).is_identity() }
+
+    /// Test if this matrix preserves handedness. That is, its determinant is
+    /// positive. A matrix that mirrors space, flipping the winding of any
+    /// triangles it transforms, is left-handed.
+    #[inline]
+    fn is_right_handed(&self) -> bool { self.determinant() > zero() }
+}
+
+/// The tolerance used by `Matrix::invert` when deciding whether a matrix is
+/// singular. By Hadamard's inequality the magnitude of the determinant is at
+/// most the product of the lengths of the columns, with equality when they
+/// are orthogonal, so the default epsilon is scaled by that product. This
+/// makes the test depend on how close the columns are to being linearly
+/// dependent, rather than on the size of the elements.
+fn singular_epsilon<S: BaseFloat>(column_lengths: &[S]) -> S {
+    let bound = column_lengths.iter().fold(one::<S>(), |p, &l| p * l);
+    let epsilon: S = ApproxEq::approx_epsilon(None::<S>);
+    epsilon * bound
 }
 
 impl<S: BaseFloat + 'static> Add<Matrix2<S>, Matrix2<S>> for Matrix2<S> { #[inline] fn add(self, other: Matrix2<S>) -> Matrix2<S> { self.add_m(&other) } }
@@ -391,10 +868,124 @@ impl<S: BaseFloat + 'static> Mul<Matrix2<S>, Matrix2<S>> for Matrix2<S> { #[inli
 impl<S: BaseFloat + 'static> Mul<Matrix3<S>, Matrix3<S>> for Matrix3<S> { #[inline] fn mul(self, other: Matrix3<S>) -> Matrix3<S> { self.mul_m(&other) } }
 impl<S: BaseFloat + 'static> Mul<Matrix4<S>, Matrix4<S>> for Matrix4<S> { #[inline] fn mul(self, other: Matrix4<S>) -> Matrix4<S> { self.mul_m(&other) } }
 
+impl<S: BaseFloat + 'static> Mul<Vector2<S>, Vector2<S>> for Matrix2<S> { #[inline] fn mul(self, v: Vector2<S>) -> Vector2<S> { self.mul_v(&v) } }
+impl<S: BaseFloat + 'static> Mul<Vector3<S>, Vector3<S>> for Matrix3<S> { #[inline] fn mul(self, v: Vector3<S>) -> Vector3<S> { self.mul_v(&v) } }
+impl<S: BaseFloat + 'static> Mul<Vector4<S>, Vector4<S>> for Matrix4<S> { #[inline] fn mul(self, v: Vector4<S>) -> Vector4<S> { self.mul_v(&v) } }
+
 impl<S: BaseFloat> One for Matrix2<S> { #[inline] fn one() -> Matrix2<S> { Matrix2::identity() } }
 impl<S: BaseFloat> One for Matrix3<S> { #[inline] fn one() -> Matrix3<S> { Matrix3::identity() } }
 impl<S: BaseFloat> One for Matrix4<S> { #[inline] fn one() -> Matrix4<S> { Matrix4::identity() } }
 
+// Matrices default to the identity rather than to zero, so that a defaulted
+// transformation leaves whatever it is applied to unchanged.
+impl<S: BaseNum> Default for Matrix2<S> { #[inline] fn default() -> Matrix2<S> { Matrix2::identity() } }
+impl<S: BaseNum> Default for Matrix3<S> { #[inline] fn default() -> Matrix3<S> { Matrix3::identity() } }
+impl<S: BaseNum> Default for Matrix4<S> { #[inline] fn default() -> Matrix4<S> { Matrix4::identity() } }
+
+// Utility macro for providing flat access to the elements of a matrix, for
+// handing it off to graphics APIs. This relies on the columns being laid out
+// contiguously, which is guaranteed by `#[repr(C)]`.
+macro_rules! flat_access(
+    ($Self:ident, $n:expr) => (
+        impl<S> $Self<S> {
+            /// The elements of the matrix in column-major order.
+            #[inline]
+            pub fn as_slice<'a>(&'a self) -> &'a [S] {
+                let flat: &'a [S; $n] = unsafe { mem::transmute(self) };
+                flat.as_slice()
+            }
+
+            /// The elements of the matrix in column-major order, mutably.
+            #[inline]
+            pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+                let flat: &'a mut [S; $n] = unsafe { mem::transmute(self) };
+                flat.as_mut_slice()
+            }
+
+            /// An iterator over the elements of the matrix in column-major
+            /// order.
+            #[inline]
+            pub fn elements<'a>(&'a self) -> slice::Iter<'a, S> {
+                self.as_slice().iter()
+            }
+
+            /// Accumulate the elements of the matrix in column-major order.
+            #[inline]
+            pub fn fold<B>(&self, init: B, op: |B, &S| -> B) -> B {
+                let mut acc = init;
+                for e in self.elements() { acc = op(acc, e); }
+                acc
+            }
+
+            /// A pointer to the first element of the matrix, for FFI.
+            #[inline]
+            pub fn as_ptr(&self) -> *const S { &self.x.x as *const S }
+
+            /// A mutable pointer to the first element of the matrix, for FFI.
+            #[inline]
+            pub fn as_mut_ptr(&mut self) -> *mut S { &mut self.x.x as *mut S }
+        }
+    )
+);
+
+flat_access!(Matrix2, 4);
+flat_access!(Matrix3, 9);
+flat_access!(Matrix4, 16);
+
+impl<S: NumCast + Copy> Matrix2<S> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Matrix2<T> {
+        Matrix2 { x: self.x.cast(), y: self.y.cast() }
+    }
+}
+
+impl<S: NumCast + Copy> Matrix3<S> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Matrix3<T> {
+        Matrix3 { x: self.x.cast(), y: self.y.cast(), z: self.z.cast() }
+    }
+}
+
+impl<S: NumCast + Copy> Matrix4<S> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Matrix4<T> {
+        Matrix4 { x: self.x.cast(), y: self.y.cast(), z: self.z.cast(), w: self.w.cast() }
+    }
+}
+
+impl<S> FromIterator<Vector2<S>> for Matrix2<S> {
+    /// Construct a matrix from the first columns of an iterator, panicking if
+    /// there are too few.
+    fn from_iter<I: Iterator<Vector2<S>>>(mut iter: I) -> Matrix2<S> {
+        Matrix2 { x: iter.next().expect("Not enough columns to construct a matrix"),
+                  y: iter.next().expect("Not enough columns to construct a matrix") }
+    }
+}
+
+impl<S> FromIterator<Vector3<S>> for Matrix3<S> {
+    /// Construct a matrix from the first columns of an iterator, panicking if
+    /// there are too few.
+    fn from_iter<I: Iterator<Vector3<S>>>(mut iter: I) -> Matrix3<S> {
+        Matrix3 { x: iter.next().expect("Not enough columns to construct a matrix"),
+                  y: iter.next().expect("Not enough columns to construct a matrix"),
+                  z: iter.next().expect("Not enough columns to construct a matrix") }
+    }
+}
+
+impl<S> FromIterator<Vector4<S>> for Matrix4<S> {
+    /// Construct a matrix from the first columns of an iterator, panicking if
+    /// there are too few.
+    fn from_iter<I: Iterator<Vector4<S>>>(mut iter: I) -> Matrix4<S> {
+        Matrix4 { x: iter.next().expect("Not enough columns to construct a matrix"),
+                  y: iter.next().expect("Not enough columns to construct a matrix"),
+                  z: iter.next().expect("Not enough columns to construct a matrix"),
+                  w: iter.next().expect("Not enough columns to construct a matrix") }
+    }
+}
+
 impl<S> FixedArray<[[S; 2]; 2]> for Matrix2<S> {
     #[inline]
     fn into_fixed(self) -> [[S; 2]; 2] {
@@ -682,8 +1273,14 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector2<S>> for Matrix2<S> {
     }
 
     fn mul_m(&self, other: &Matrix2<S>) -> Matrix2<S> {
-        Matrix2::new(self.row(0).dot(&other[0]), self.row(1).dot(&other[0]),
-                     self.row(0).dot(&other[1]), self.row(1).dot(&other[1]))
+        let (r0, r1) = (self.row(0), self.row(1));
+        Matrix2::new(r0.dot(&other[0]), r1.dot(&other[0]),
+                     r0.dot(&other[1]), r1.dot(&other[1]))
+    }
+
+    fn transpose_mul(&self, other: &Matrix2<S>) -> Matrix2<S> {
+        Matrix2::new(self[0].dot(&other[0]), self[1].dot(&other[0]),
+                     self[0].dot(&other[1]), self[1].dot(&other[1]))
     }
 
     #[inline]
@@ -743,14 +1340,24 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector2<S>> for Matrix2<S> {
                      self[1][1])
     }
 
+    #[inline]
+    fn adjugate(&self) -> Matrix2<S> {
+        Matrix2::new( self[1][1], -self[0][1],
+                     -self[1][0],  self[0][0])
+    }
+
     #[inline]
     fn invert(&self) -> Option<Matrix2<S>> {
+        self.invert_with_epsilon(singular_epsilon(&[self.x.length(), self.y.length()]))
+    }
+
+    #[inline]
+    fn invert_with_epsilon(&self, epsilon: S) -> Option<Matrix2<S>> {
         let det = self.determinant();
-        if det.approx_eq(&zero()) {
+        if det.abs() <= epsilon {
             None
         } else {
-            Some(Matrix2::new( self[1][1] / det, -self[0][1] / det,
-                              -self[1][0] / det,  self[0][0] / det))
+            Some(self.adjugate().div_s(det))
         }
     }
 
@@ -766,6 +1373,19 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector2<S>> for Matrix2<S> {
         (&self[0][1]).approx_eq(&self[1][0]) &&
         (&self[1][0]).approx_eq(&self[0][1])
     }
+
+    fn is_rotated(&self) -> bool {
+        // the eigenvalues of a symmetric 2 x 2 matrix are both negative
+        // exactly when its determinant is positive and its trace negative
+        !self.is_symmetric() ||
+        (self.determinant() > zero() && self[0][0] + self[1][1] < zero())
+    }
+
+    fn orthonormalize(&self) -> Matrix2<S> {
+        let x = self[0].normalize();
+        let y = self[1].reject_from(&x).normalize();
+        Matrix2::from_cols(x, y)
+    }
 }
 
 impl<S: BaseFloat + 'static> Matrix<S, Vector3<S>> for Matrix3<S> {
@@ -812,9 +1432,16 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector3<S>> for Matrix3<S> {
     }
 
     fn mul_m(&self, other: &Matrix3<S>) -> Matrix3<S> {
-        Matrix3::new(self.row(0).dot(&other[0]),self.row(1).dot(&other[0]),self.row(2).dot(&other[0]),
-                     self.row(0).dot(&other[1]),self.row(1).dot(&other[1]),self.row(2).dot(&other[1]),
-                     self.row(0).dot(&other[2]),self.row(1).dot(&other[2]),self.row(2).dot(&other[2]))
+        let (r0, r1, r2) = (self.row(0), self.row(1), self.row(2));
+        Matrix3::new(r0.dot(&other[0]), r1.dot(&other[0]), r2.dot(&other[0]),
+                     r0.dot(&other[1]), r1.dot(&other[1]), r2.dot(&other[1]),
+                     r0.dot(&other[2]), r1.dot(&other[2]), r2.dot(&other[2]))
+    }
+
+    fn transpose_mul(&self, other: &Matrix3<S>) -> Matrix3<S> {
+        Matrix3::new(self[0].dot(&other[0]), self[1].dot(&other[0]), self[2].dot(&other[0]),
+                     self[0].dot(&other[1]), self[1].dot(&other[1]), self[2].dot(&other[1]),
+                     self[0].dot(&other[2]), self[1].dot(&other[2]), self[2].dot(&other[2]))
     }
 
     #[inline]
@@ -885,12 +1512,21 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector3<S>> for Matrix3<S> {
                      self[2][2])
     }
 
+    fn adjugate(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self[1].cross(&self[2]),
+                           self[2].cross(&self[0]),
+                           self[0].cross(&self[1])).transpose()
+    }
+
     fn invert(&self) -> Option<Matrix3<S>> {
+        self.invert_with_epsilon(singular_epsilon(&[self.x.length(), self.y.length(),
+                                                    self.z.length()]))
+    }
+
+    fn invert_with_epsilon(&self, epsilon: S) -> Option<Matrix3<S>> {
         let det = self.determinant();
-        if det.approx_eq(&zero()) { None } else {
-            Some(Matrix3::from_cols(self[1].cross(&self[2]).div_s(det),
-                                    self[2].cross(&self[0]).div_s(det),
-                                    self[0].cross(&self[1]).div_s(det)).transpose())
+        if det.abs() <= epsilon { None } else {
+            Some(self.adjugate().div_s(det))
         }
     }
 
@@ -915,6 +1551,25 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector3<S>> for Matrix3<S> {
         (&self[2][0]).approx_eq(&self[0][2]) &&
         (&self[2][1]).approx_eq(&self[1][2])
     }
+
+    fn is_rotated(&self) -> bool {
+        if !self.is_symmetric() { return true; }
+
+        // ignore eigenvalues that are only negative because of rounding,
+        // using the same tolerance as `symmetric_eigen`
+        let (e, _) = self.symmetric_eigen();
+        let epsilon: S = ApproxEq::approx_epsilon(None::<S>);
+        let tolerance = -epsilon * (e.x * e.x + e.y * e.y + e.z * e.z).sqrt();
+        let negative = [e.x, e.y, e.z].iter().filter(|&&x| x < tolerance).count();
+        negative > 1
+    }
+
+    fn orthonormalize(&self) -> Matrix3<S> {
+        let x = self[0].normalize();
+        let y = self[1].reject_from(&x).normalize();
+        let z = self[2].reject_from(&x).reject_from(&y).normalize();
+        Matrix3::from_cols(x, y, z)
+    }
 }
 
 // Using self.row(0).dot(other[0]) like the other matrix multiplies
@@ -985,6 +1640,13 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector4<S>> for Matrix4<S> {
                      dot_matrix4!(self, other, 0, 3), dot_matrix4!(self, other, 1, 3), dot_matrix4!(self, other, 2, 3), dot_matrix4!(self, other, 3, 3))
     }
 
+    fn transpose_mul(&self, other: &Matrix4<S>) -> Matrix4<S> {
+        Matrix4::new(self[0].dot(&other[0]), self[1].dot(&other[0]), self[2].dot(&other[0]), self[3].dot(&other[0]),
+                     self[0].dot(&other[1]), self[1].dot(&other[1]), self[2].dot(&other[1]), self[3].dot(&other[1]),
+                     self[0].dot(&other[2]), self[1].dot(&other[2]), self[2].dot(&other[2]), self[3].dot(&other[2]),
+                     self[0].dot(&other[3]), self[1].dot(&other[3]), self[2].dot(&other[3]), self[3].dot(&other[3]))
+    }
+
     #[inline]
     fn neg_self(&mut self) {
         (&mut self[0]).neg_self();
@@ -1077,37 +1739,46 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector4<S>> for Matrix4<S> {
                      self[3][3])
     }
 
-    fn invert(&self) -> Option<Matrix4<S>> {
-        let det = self.determinant();
-        if !det.approx_eq(&zero()) {
-            let one: S = one();
-            let inv_det = one / det;
-            let t = self.transpose();
-            let cf = |i, j| {
-                let mat = match i {
-                    0 => Matrix3::from_cols(t.y.truncate_n(j),
-                                            t.z.truncate_n(j),
-                                            t.w.truncate_n(j)),
-                    1 => Matrix3::from_cols(t.x.truncate_n(j),
-                                            t.z.truncate_n(j),
-                                            t.w.truncate_n(j)),
-                    2 => Matrix3::from_cols(t.x.truncate_n(j),
-                                            t.y.truncate_n(j),
-                                            t.w.truncate_n(j)),
-                    3 => Matrix3::from_cols(t.x.truncate_n(j),
-                                            t.y.truncate_n(j),
-                                            t.z.truncate_n(j)),
-                    _ => panic!("out of range")
-                };
-                let sign = if (i+j) & 1 == 1 {-one} else {one};
-                mat.determinant() * sign * inv_det
+    fn adjugate(&self) -> Matrix4<S> {
+        let one: S = one();
+        let t = self.transpose();
+        let cf = |i, j| {
+            let mat = match i {
+                0 => Matrix3::from_cols(t.y.truncate_n(j),
+                                        t.z.truncate_n(j),
+                                        t.w.truncate_n(j)),
+                1 => Matrix3::from_cols(t.x.truncate_n(j),
+                                        t.z.truncate_n(j),
+                                        t.w.truncate_n(j)),
+                2 => Matrix3::from_cols(t.x.truncate_n(j),
+                                        t.y.truncate_n(j),
+                                        t.w.truncate_n(j)),
+                3 => Matrix3::from_cols(t.x.truncate_n(j),
+                                        t.y.truncate_n(j),
+                                        t.z.truncate_n(j)),
+                _ => panic!("out of range")
             };
+            let sign = if (i+j) & 1 == 1 {-one} else {one};
+            mat.determinant() * sign
+        };
 
-            Some(Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                              cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                              cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                              cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3)))
+        Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+                     cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+                     cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+                     cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3))
+    }
+
+    fn invert(&self) -> Option<Matrix4<S>> {
+        // a translation never makes a matrix singular, so the translation
+        // column is left out of the bound
+        self.invert_with_epsilon(singular_epsilon(&[self.x.length(), self.y.length(),
+                                                    self.z.length()]))
+    }
 
+    fn invert_with_epsilon(&self, epsilon: S) -> Option<Matrix4<S>> {
+        let det = self.determinant();
+        if det.abs() > epsilon {
+            Some(self.adjugate().div_s(det))
         } else {
             None
         }
@@ -1148,6 +1819,17 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector4<S>> for Matrix4<S> {
         (&self[3][1]).approx_eq(&self[1][3]) &&
         (&self[3][2]).approx_eq(&self[2][3])
     }
+
+    #[inline]
+    fn is_rotated(&self) -> bool { self.truncate().is_rotated() }
+
+    fn orthonormalize(&self) -> Matrix4<S> {
+        let x = self[0].normalize();
+        let y = self[1].reject_from(&x).normalize();
+        let z = self[2].reject_from(&x).reject_from(&y).normalize();
+        let w = self[3].reject_from(&x).reject_from(&y).reject_from(&z).normalize();
+        Matrix4::from_cols(x, y, z, w)
+    }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Matrix2<S> {
@@ -1156,6 +1838,18 @@ impl<S: BaseFloat> ApproxEq<S> for Matrix2<S> {
         self[0].approx_eq_eps(&other[0], epsilon) &&
         self[1].approx_eq_eps(&other[1], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix2<S>, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], max_relative) &&
+        self[1].relative_eq(&other[1], max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix2<S>, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], max_ulps) &&
+        self[1].ulps_eq(&other[1], max_ulps)
+    }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Matrix3<S> {
@@ -1165,6 +1859,20 @@ impl<S: BaseFloat> ApproxEq<S> for Matrix3<S> {
         self[1].approx_eq_eps(&other[1], epsilon) &&
         self[2].approx_eq_eps(&other[2], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix3<S>, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], max_relative) &&
+        self[1].relative_eq(&other[1], max_relative) &&
+        self[2].relative_eq(&other[2], max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix3<S>, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], max_ulps) &&
+        self[1].ulps_eq(&other[1], max_ulps) &&
+        self[2].ulps_eq(&other[2], max_ulps)
+    }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Matrix4<S> {
@@ -1175,6 +1883,22 @@ impl<S: BaseFloat> ApproxEq<S> for Matrix4<S> {
         self[2].approx_eq_eps(&other[2], epsilon) &&
         self[3].approx_eq_eps(&other[3], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix4<S>, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], max_relative) &&
+        self[1].relative_eq(&other[1], max_relative) &&
+        self[2].relative_eq(&other[2], max_relative) &&
+        self[3].relative_eq(&other[3], max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix4<S>, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], max_ulps) &&
+        self[1].ulps_eq(&other[1], max_ulps) &&
+        self[2].ulps_eq(&other[2], max_ulps) &&
+        self[3].ulps_eq(&other[3], max_ulps)
+    }
 }
 
 // Conversion traits
@@ -1246,59 +1970,80 @@ impl<S: BaseFloat + 'static> ToQuaternion<S> for Matrix3<S> {
                 Quaternion::new(w, x, y, z)
             }
             () if (self[0][0] > self[1][1]) && (self[0][0] > self[2][2]) => {
-                let s = (half + (self[0][0] - self[1][1] - self[2][2])).sqrt();
-                let w = half * s;
+                let s = (one::<S>() + self[0][0] - self[1][1] - self[2][2]).sqrt();
+                let x = half * s;
                 let s = half / s;
-                let x = (self[0][1] - self[1][0]) * s;
-                let y = (self[2][0] - self[0][2]) * s;
-                let z = (self[1][2] - self[2][1]) * s;
+                let w = (self[1][2] - self[2][1]) * s;
+                let y = (self[0][1] + self[1][0]) * s;
+                let z = (self[2][0] + self[0][2]) * s;
                 Quaternion::new(w, x, y, z)
             }
             () if self[1][1] > self[2][2] => {
-                let s = (half + (self[1][1] - self[0][0] - self[2][2])).sqrt();
-                let w = half * s;
+                let s = (one::<S>() + self[1][1] - self[0][0] - self[2][2]).sqrt();
+                let y = half * s;
                 let s = half / s;
-                let x = (self[0][1] - self[1][0]) * s;
-                let y = (self[1][2] - self[2][1]) * s;
-                let z = (self[2][0] - self[0][2]) * s;
+                let w = (self[2][0] - self[0][2]) * s;
+                let x = (self[0][1] + self[1][0]) * s;
+                let z = (self[1][2] + self[2][1]) * s;
                 Quaternion::new(w, x, y, z)
             }
             () => {
-                let s = (half + (self[2][2] - self[0][0] - self[1][1])).sqrt();
-                let w = half * s;
+                let s = (one::<S>() + self[2][2] - self[0][0] - self[1][1]).sqrt();
+                let z = half * s;
                 let s = half / s;
-                let x = (self[2][0] - self[0][2]) * s;
-                let y = (self[1][2] - self[2][1]) * s;
-                let z = (self[0][1] - self[1][0]) * s;
+                let w = (self[0][1] - self[1][0]) * s;
+                let x = (self[2][0] + self[0][2]) * s;
+                let y = (self[1][2] + self[2][1]) * s;
                 Quaternion::new(w, x, y, z)
             }
         }
     }
 }
 
+/// Write the elements of a matrix in row-major visual order, one row per line,
+/// padding each column to the width of its widest element.
+fn fmt_rows(f: &mut fmt::Formatter, rows: &[Vec<String>]) -> fmt::Result {
+    let widths: Vec<uint> = range(0, rows[0].len()).map(|c| {
+        rows.iter().map(|row| row[c].len()).max().unwrap_or(0)
+    }).collect();
+
+    try!(write!(f, "["));
+    for (r, row) in rows.iter().enumerate() {
+        if r > 0 { try!(write!(f, ",\n ")); }
+        try!(write!(f, "["));
+        for (c, elem) in row.iter().enumerate() {
+            if c > 0 { try!(write!(f, ", ")); }
+            for _ in range(elem.len(), widths[c]) { try!(write!(f, " ")); }
+            try!(write!(f, "{}", elem));
+        }
+        try!(write!(f, "]"));
+    }
+    write!(f, "]")
+}
+
 impl<S: BaseNum> fmt::Show for Matrix2<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[[{}, {}], [{}, {}]]",
-                self[0][0], self[0][1],
-                self[1][0], self[1][1])
+        let rows: Vec<Vec<String>> = range(0u, 2).map(|r| {
+            range(0u, 2).map(|c| format!("{}", self[c][r])).collect()
+        }).collect();
+        fmt_rows(f, rows.as_slice())
     }
 }
 
 impl<S: BaseNum> fmt::Show for Matrix3<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[[{}, {}, {}], [{}, {}, {}], [{}, {}, {}]]",
-                self[0][0], self[0][1], self[0][2],
-                self[1][0], self[1][1], self[1][2],
-                self[2][0], self[2][1], self[2][2])
+        let rows: Vec<Vec<String>> = range(0u, 3).map(|r| {
+            range(0u, 3).map(|c| format!("{}", self[c][r])).collect()
+        }).collect();
+        fmt_rows(f, rows.as_slice())
     }
 }
 
 impl<S: BaseNum> fmt::Show for Matrix4<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[[{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}], [{}, {}, {}, {}]]",
-                self[0][0], self[0][1], self[0][2], self[0][3],
-                self[1][0], self[1][1], self[1][2], self[1][3],
-                self[2][0], self[2][1], self[2][2], self[2][3],
-                self[3][0], self[3][1], self[3][2], self[3][3])
+        let rows: Vec<Vec<String>> = range(0u, 4).map(|r| {
+            range(0u, 4).map(|c| format!("{}", self[c][r])).collect()
+        }).collect();
+        fmt_rows(f, rows.as_slice())
     }
 }
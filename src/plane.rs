@@ -17,7 +17,7 @@ use std::fmt;
 
 use approx::ApproxEq;
 use intersect::Intersect;
-use num::{BaseFloat, Zero, zero};
+use num::{BaseFloat, zero};
 use point::{Point, Point3};
 use ray::Ray3;
 use vector::{Vector3, Vector4};
@@ -83,7 +83,7 @@ impl<S: BaseFloat> Plane<S> {
         else {
             // compute the normal and the distance to the plane
             n.normalize_self();
-            let d = -a.dot(&n);
+            let d = a.dot(&n);
 
             Some(Plane::new(n, d))
         }
@@ -94,15 +94,42 @@ impl<S: BaseFloat> Plane<S> {
     pub fn from_point_normal(p: Point3<S>, n: Vector3<S>) -> Plane<S> {
         Plane { n: n, d: p.dot(&n) }
     }
+
+    /// The signed distance from the plane to the point `p`. This is positive
+    /// when `p` is on the side of the plane that the normal points towards,
+    /// negative when it is behind, and zero when `p` lies on the plane.
+    ///
+    /// The result is only a true distance if the normal has unit length.
+    #[inline]
+    pub fn distance(&self, p: &Point3<S>) -> S {
+        p.dot(&self.n) - self.d
+    }
+
+    /// Rescale the plane equation so that the normal has unit length. The
+    /// plane itself is unchanged.
+    pub fn normalize(&self) -> Plane<S> {
+        let len = self.n.length();
+        Plane { n: self.n.div_s(len), d: self.d / len }
+    }
+
+    /// Find the parameter `t` at which `ray` crosses the plane, such that
+    /// `ray.at(t)` lies on the plane. Returns `None` if the ray is parallel to
+    /// the plane.
+    ///
+    /// A negative `t` means the plane is behind the ray's origin.
+    pub fn intersect_ray(&self, ray: &Ray3<S>) -> Option<S> {
+        let denom = ray.direction.dot(&self.n);
+        if denom.approx_eq(&zero()) { None }
+        else { Some((self.d - ray.origin.dot(&self.n)) / denom) }
+    }
 }
 
 impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Plane<S>, Ray3<S>) {
     fn intersection(&self) -> Option<Point3<S>> {
         match *self {
-            (ref p, ref r) => {
-                let t = -(p.d + r.origin.dot(&p.n)) / r.direction.dot(&p.n);
-                if t < Zero::zero() { None }
-                else { Some(r.origin.add_v(&r.direction.mul_s(t))) }
+            (ref p, ref r) => match p.intersect_ray(r) {
+                Some(t) if t >= zero() => Some(r.at(t)),
+                _ => None,
             }
         }
     }
@@ -127,6 +154,18 @@ ApproxEq<S> for Plane<S> {
         self.n.approx_eq_eps(&other.n, epsilon) &&
         self.d.approx_eq_eps(&other.d, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Plane<S>, max_relative: &S) -> bool {
+        self.n.relative_eq(&other.n, max_relative) &&
+        self.d.relative_eq(&other.d, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Plane<S>, max_ulps: u32) -> bool {
+        self.n.ulps_eq(&other.n, max_ulps) &&
+        self.d.ulps_eq(&other.d, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> fmt::Show for Plane<S> {
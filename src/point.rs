@@ -49,6 +49,19 @@ impl<S: BaseNum> Point3<S> {
     }
 }
 
+impl<S: BaseNum> Point2<S> {
+    #[inline]
+    pub fn from_homogeneous(v: &Vector3<S>) -> Point2<S> {
+        let e = v.truncate().mul_s(one::<S>() / v.z);
+        Point2::new(e.x, e.y)
+    }
+
+    #[inline]
+    pub fn to_homogeneous(&self) -> Vector3<S> {
+        Vector3::new(self.x, self.y, one())
+    }
+}
+
 impl<S: BaseNum> Point3<S> {
     #[inline]
     pub fn from_homogeneous(v: &Vector4<S>) -> Point3<S> {
@@ -254,6 +267,18 @@ impl<S: BaseFloat> ApproxEq<S> for Point2<S> {
         self.x.approx_eq_eps(&other.x, epsilon) &&
         self.y.approx_eq_eps(&other.y, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Point2<S>, max_relative: &S) -> bool {
+        self.x.relative_eq(&other.x, max_relative) &&
+        self.y.relative_eq(&other.y, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Point2<S>, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, max_ulps) &&
+        self.y.ulps_eq(&other.y, max_ulps)
+    }
 }
 
 impl<S> FixedArray<[S; 3]> for Point3<S> {
@@ -423,6 +448,39 @@ impl<S: BaseFloat> ApproxEq<S> for Point3<S> {
         self.y.approx_eq_eps(&other.y, epsilon) &&
         self.z.approx_eq_eps(&other.z, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Point3<S>, max_relative: &S) -> bool {
+        self.x.relative_eq(&other.x, max_relative) &&
+        self.y.relative_eq(&other.y, max_relative) &&
+        self.z.relative_eq(&other.z, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Point3<S>, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, max_ulps) &&
+        self.y.ulps_eq(&other.y, max_ulps) &&
+        self.z.ulps_eq(&other.z, max_ulps)
+    }
+}
+
+// Points can be offset by a vector, and the difference between two points is
+// a vector. Adding two points together is meaningless, so is not provided.
+
+impl<S: BaseNum> Add<Vector2<S>, Point2<S>> for Point2<S> {
+    #[inline] fn add(self, v: Vector2<S>) -> Point2<S> { self.add_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Point2<S>, Vector2<S>> for Point2<S> {
+    #[inline] fn sub(self, p: Point2<S>) -> Vector2<S> { self.sub_p(&p) }
+}
+
+impl<S: BaseNum> Add<Vector3<S>, Point3<S>> for Point3<S> {
+    #[inline] fn add(self, v: Vector3<S>) -> Point3<S> { self.add_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Point3<S>, Vector3<S>> for Point3<S> {
+    #[inline] fn sub(self, p: Point3<S>) -> Vector3<S> { self.sub_p(&p) }
 }
 
 impl<S: BaseNum> fmt::Show for Point2<S> {
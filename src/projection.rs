@@ -25,6 +25,10 @@ use plane::Plane;
 ///
 /// This is the equivalent to the [gluPerspective]
 /// (http://www.opengl.org/sdk/docs/man2/xhtml/gluPerspective.xml) function.
+///
+/// If `near == far` the depth range is empty and cannot be mapped onto the
+/// `[-1, 1]` clip range, so the returned matrix is degenerate: every point is
+/// given a clip-space depth of zero.
 pub fn perspective<S: BaseFloat + 'static, A: Angle<S>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
     PerspectiveFov {
         fovy:   fovy,
@@ -38,6 +42,8 @@ pub fn perspective<S: BaseFloat + 'static, A: Angle<S>>(fovy: A, aspect: S, near
 ///
 /// This is the equivalent of the now deprecated [glFrustrum]
 /// (http://www.opengl.org/sdk/docs/man2/xhtml/glFrustum.xml) function.
+///
+/// If `near == far` the returned matrix is degenerate, as with `perspective`.
 pub fn frustum<S: BaseFloat + 'static>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
     Perspective {
         left:   left,
@@ -53,6 +59,9 @@ pub fn frustum<S: BaseFloat + 'static>(left: S, right: S, bottom: S, top: S, nea
 ///
 /// This is the equivalent of the now deprecated [glOrtho]
 /// (http://www.opengl.org/sdk/docs/man2/xhtml/glOrtho.xml) function.
+///
+/// If `near == far` the returned matrix is degenerate: every point is mapped
+/// to a depth of zero.
 pub fn ortho<S: BaseFloat + 'static>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
     Ortho {
         left:   left,
@@ -110,7 +119,7 @@ impl<S: BaseFloat, A: Angle<S>> ToMatrix4<S> for PerspectiveFov<S, A> {
         assert!(self.aspect > zero(),    "The aspect ratio cannot be below zero, found: {}", self.aspect);
         assert!(self.near   > zero(),    "The near plane distance cannot be below zero, found: {}", self.near);
         assert!(self.far    > zero(),    "The far plane distance cannot be below zero, found: {}", self.far);
-        assert!(self.far    > self.near, "The far plane cannot be closer than the near plane, found: far: {}, near: {}", self.far, self.near);
+        assert!(self.far   >= self.near, "The far plane cannot be closer than the near plane, found: far: {}, near: {}", self.far, self.near);
 
         let f = cot(self.fovy.div_s(cast(2i).unwrap()).to_rad());
         let two: S = cast(2i).unwrap();
@@ -125,14 +134,20 @@ impl<S: BaseFloat, A: Angle<S>> ToMatrix4<S> for PerspectiveFov<S, A> {
         let c1r2 = zero();
         let c1r3 = zero();
 
+        // An empty depth range collapses every depth onto zero
+        let (c2r2, c3r2) = if self.near == self.far {
+            (zero(), zero())
+        } else {
+            ((self.far + self.near) / (self.near - self.far),
+             (two * self.far * self.near) / (self.near - self.far))
+        };
+
         let c2r0 = zero();
         let c2r1 = zero();
-        let c2r2 = (self.far + self.near) / (self.near - self.far);
         let c2r3 = -one::<S>();
 
         let c3r0 = zero();
         let c3r1 = zero();
-        let c3r2 = (two * self.far * self.near) / (self.near - self.far);
         let c3r3 = zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -145,9 +160,9 @@ impl<S: BaseFloat, A: Angle<S>> ToMatrix4<S> for PerspectiveFov<S, A> {
 /// A perspective projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct Perspective<S> {
-    pub left:   S,  right:  S,
-    pub bottom: S,  top:    S,
-    pub near:   S,  far:    S,
+    pub left:   S,  pub right:  S,
+    pub bottom: S,  pub top:    S,
+    pub near:   S,  pub far:    S,
 }
 
 impl<S: BaseFloat + 'static> Projection<S> for Perspective<S> {
@@ -175,14 +190,20 @@ impl<S: BaseFloat + 'static> ToMatrix4<S> for Perspective<S> {
         let c1r2 = zero();
         let c1r3 = zero();
 
+        // An empty depth range collapses every depth onto zero
+        let (c2r2, c3r2) = if self.near == self.far {
+            (zero(), zero())
+        } else {
+            (-(self.far + self.near) / (self.far - self.near),
+             -(two * self.far * self.near) / (self.far - self.near))
+        };
+
         let c2r0 = (self.right + self.left) / (self.right - self.left);
         let c2r1 = (self.top + self.bottom) / (self.top - self.bottom);
-        let c2r2 = -(self.far + self.near) / (self.far - self.near);
         let c2r3 = -one::<S>();
 
         let c3r0 = zero();
         let c3r1 = zero();
-        let c3r2 = -(two * self.far * self.near) / (self.far - self.near);
         let c3r3 = zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -195,20 +216,20 @@ impl<S: BaseFloat + 'static> ToMatrix4<S> for Perspective<S> {
 /// An orthographic projection with arbitrary left/right/bottom/top distances
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct Ortho<S> {
-    pub left:   S,  right:  S,
-    pub bottom: S,  top:    S,
-    pub near:   S,  far:    S,
+    pub left:   S,  pub right:  S,
+    pub bottom: S,  pub top:    S,
+    pub near:   S,  pub far:    S,
 }
 
 impl<S: BaseFloat> Projection<S> for Ortho<S> {
     fn to_frustum(&self) -> Frustum<S> {
         Frustum {
-            left:   Plane::from_abcd( one::<S>(), zero::<S>(), zero::<S>(), self.left.clone()),
-            right:  Plane::from_abcd(-one::<S>(), zero::<S>(), zero::<S>(), self.right.clone()),
-            bottom: Plane::from_abcd(zero::<S>(),  one::<S>(), zero::<S>(), self.bottom.clone()),
-            top:    Plane::from_abcd(zero::<S>(), -one::<S>(), zero::<S>(), self.top.clone()),
-            near:   Plane::from_abcd(zero::<S>(), zero::<S>(), -one::<S>(), self.near.clone()),
-            far:    Plane::from_abcd(zero::<S>(), zero::<S>(),  one::<S>(), self.far.clone()),
+            left:   Plane::from_abcd( one::<S>(), zero::<S>(), zero::<S>(),  self.left.clone()),
+            right:  Plane::from_abcd(-one::<S>(), zero::<S>(), zero::<S>(), -self.right.clone()),
+            bottom: Plane::from_abcd(zero::<S>(),  one::<S>(), zero::<S>(),  self.bottom.clone()),
+            top:    Plane::from_abcd(zero::<S>(), -one::<S>(), zero::<S>(), -self.top.clone()),
+            near:   Plane::from_abcd(zero::<S>(), zero::<S>(), -one::<S>(),  self.near.clone()),
+            far:    Plane::from_abcd(zero::<S>(), zero::<S>(),  one::<S>(), -self.far.clone()),
         }
     }
 }
@@ -227,14 +248,20 @@ impl<S: BaseFloat> ToMatrix4<S> for Ortho<S> {
         let c1r2 = zero();
         let c1r3 = zero();
 
+        // An empty depth range collapses every depth onto zero
+        let (c2r2, c3r2) = if self.near == self.far {
+            (zero(), zero())
+        } else {
+            (-two / (self.far - self.near),
+             -(self.far + self.near) / (self.far - self.near))
+        };
+
         let c2r0 = zero();
         let c2r1 = zero();
-        let c2r2 = -two / (self.far - self.near);
         let c2r3 = zero();
 
         let c3r0 = -(self.right + self.left) / (self.right - self.left);
         let c3r1 = -(self.top + self.bottom) / (self.top - self.bottom);
-        let c3r2 = -(self.far + self.near) / (self.far - self.near);
         let c3r3 = one::<S>();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -15,9 +15,9 @@
 
 use std::fmt;
 use std::mem;
-use std::f64;
 use std::num::{cast, Float};
 use std::ops::*;
+use std::rand::{Rand, Rng};
 
 use angle::{Angle, Rad, acos, sin, sin_cos, rad};
 use approx::ApproxEq;
@@ -27,6 +27,7 @@ use num::{BaseFloat, one, zero};
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3, ToBasis3};
 use vector::{Vector3, Vector, EuclideanVector};
+use vector::perpendicular;
 
 /// A [quaternion](https://en.wikipedia.org/wiki/Quaternion) in scalar/vector
 /// form.
@@ -123,7 +124,8 @@ impl<S: BaseFloat> Quaternion<S> {
         Quaternion::from_sv(self.s - other.s, self.v - other.v)
     }
 
-    /// The result of multipliplying the quaternion by `other`
+    /// The result of multipliplying the quaternion by `other` (the Hamilton
+    /// product). The resulting rotation applies `other` first, then `self`.
     pub fn mul_q(&self, other: &Quaternion<S>) -> Quaternion<S> {
         Quaternion::new(self.s * other.s - self.v.x * other.v.x - self.v.y * other.v.y - self.v.z * other.v.z,
                         self.s * other.v.x + self.v.x * other.s + self.v.y * other.v.z - self.v.z * other.v.y,
@@ -162,8 +164,7 @@ impl<S: BaseFloat> Quaternion<S> {
     /// Multiply this quaternion by another, in-place.
     #[inline]
     pub fn mul_self_q(&mut self, q: &Quaternion<S>) {
-        self.s = self.s * q.s;
-        self.v.mul_self_v(&q.v);
+        *self = self.mul_q(q);
     }
 
     /// The dot product of the quaternion and `q`.
@@ -204,10 +205,48 @@ impl<S: BaseFloat> Quaternion<S> {
         self.mul_s(one::<S>() / self.magnitude())
     }
 
+    /// The multiplicative inverse of the quaternion, or `None` if its
+    /// magnitude is zero. For unit quaternions this is equal to the conjugate.
+    ///
+    /// Unlike `Rotation::invert`, which assumes a rotation and so a non-zero
+    /// quaternion, this checks for the zero quaternion instead of returning
+    /// NaNs.
+    #[inline]
+    pub fn checked_invert(&self) -> Option<Quaternion<S>> {
+        let magnitude2 = self.magnitude2();
+        if magnitude2 == zero() {
+            None
+        } else {
+            Some(self.conjugate().div_s(magnitude2))
+        }
+    }
+
     /// Do a normalized linear interpolation with `other`, by `amount`.
+    ///
+    /// This is much cheaper than `slerp`, but does not interpolate at a
+    /// constant angular velocity. If the quaternions are more than a half
+    /// turn apart, `other` is negated so that the shorter arc is taken, in
+    /// which case an `amount` of one gives `-other`, which is the same
+    /// rotation.
     pub fn nlerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
+        let other = if self.dot(other) < zero() { -*other } else { *other };
         self.mul_s(one::<S>() - amount).add_q(&other.mul_s(amount)).normalize()
     }
+
+    /// Check whether this quaternion and `other` approximately represent
+    /// the same rotation. Unlike `approx_eq`, this treats `q` and `-q` as
+    /// equal, since both rotate vectors in the same way.
+    #[inline]
+    pub fn approx_eq_rotation(&self, other: &Quaternion<S>) -> bool {
+        self.approx_eq(other) || self.approx_eq(&-*other)
+    }
+}
+
+impl<S: BaseFloat> Default for Quaternion<S> {
+    /// The identity rotation, for the same reason that matrices default to
+    /// the identity: a defaulted quaternion leaves vectors unchanged.
+    #[inline]
+    fn default() -> Quaternion<S> { Quaternion::identity() }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Quaternion<S> {
@@ -216,6 +255,88 @@ impl<S: BaseFloat> ApproxEq<S> for Quaternion<S> {
         self.s.approx_eq_eps(&other.s, epsilon) &&
         self.v.approx_eq_eps(&other.v, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Quaternion<S>, max_relative: &S) -> bool {
+        self.s.relative_eq(&other.s, max_relative) &&
+        self.v.relative_eq(&other.v, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Quaternion<S>, max_ulps: u32) -> bool {
+        self.s.ulps_eq(&other.s, max_ulps) &&
+        self.v.ulps_eq(&other.v, max_ulps)
+    }
+}
+
+impl<S: BaseFloat + 'static> Quaternion<S> {
+    /// Create the shortest-arc rotation that maps the direction of `from`
+    /// onto the direction of `to`. Neither vector needs to be normalized.
+    ///
+    /// If the vectors point in opposite directions, to within a few ulps,
+    /// there is no unique shortest arc, so this returns a half turn around an
+    /// arbitrary axis perpendicular to `from`.
+    pub fn from_rotation_between(from: &Vector3<S>, to: &Vector3<S>) -> Quaternion<S> {
+        //http://stackoverflow.com/questions/1171849/
+        //finding-quaternion-representing-the-rotation-from-one-vector-to-another
+        let a = from.normalize();
+        let b = to.normalize();
+        let k = one::<S>() + a.dot(&b);
+
+        // only treat the vectors as opposite when `k` is lost to rounding, as
+        // nearly opposite vectors still have a well-defined shortest arc
+        let epsilon: S = Float::epsilon();
+        if k <= epsilon * cast(4i).unwrap() {
+            Rotation3::from_axis_angle(&perpendicular(&a), Angle::turn_div_2())
+        } else {
+            Quaternion::from_sv(k, a.cross(&b)).normalize()
+        }
+    }
+
+    /// Convert the rotation to a normalized axis and an angle in the range
+    /// `[0, 2pi]` around it. The quaternion does not need to be normalized.
+    ///
+    /// A rotation by zero has no meaningful axis, so the identity returns the
+    /// `x` axis with an angle of zero.
+    pub fn to_axis_angle(&self) -> (Vector3<S>, Rad<S>) {
+        let q = self.normalize();
+        let sin_half = q.v.length();
+        if sin_half.approx_eq(&zero()) {
+            (Vector3::unit_x(), rad(zero()))
+        } else {
+            let two: S = cast(2i).unwrap();
+            (q.v.div_s(sin_half), rad(two * sin_half.atan2(q.s)))
+        }
+    }
+
+    /// Extract the euler angles `(x, y, z)` of the rotation, in the
+    /// convention used by `Rotation3::from_euler` and `Matrix3::from_euler`:
+    /// the rotations are applied about the fixed `x`, then `y`, then `z`
+    /// axes. This gives the same angles as `Matrix3::to_euler` on the
+    /// equivalent matrix, including the handling of gimbal lock, which is
+    /// only assumed when `y` is within a few ulps of `pi/2` or `-pi/2`.
+    ///
+    /// The quaternion does not need to be normalized.
+    pub fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>) {
+        self.normalize().to_matrix3().to_euler()
+    }
+}
+
+impl<S: BaseFloat + Rand> Quaternion<S> {
+    /// Generate a random unit quaternion, uniformly distributed over the space
+    /// of rotations, using Shoemake's method.
+    ///
+    /// - [Uniform Random Rotations, Graphics Gems III]
+    ///   (http://www.sciencedirect.com/science/article/pii/B9780080507552500361)
+    pub fn rand_rotation<R: Rng>(rng: &mut R) -> Quaternion<S> {
+        let full_turn: Rad<S> = Angle::full_turn();
+        let u1 = rng.gen::<S>();
+        let (s2, c2) = sin_cos(full_turn.mul_s(rng.gen::<S>()));
+        let (s3, c3) = sin_cos(full_turn.mul_s(rng.gen::<S>()));
+        let a = (one::<S>() - u1).sqrt();
+        let b = u1.sqrt();
+        Quaternion::new(b * c3, a * s2, a * c2, b * s3)
+    }
 }
 
 impl<S: BaseFloat> Quaternion<S> {
@@ -224,6 +345,10 @@ impl<S: BaseFloat> Quaternion<S> {
     /// Return the spherical linear interpolation between the quaternion and
     /// `other`. Both quaternions should be normalized first.
     ///
+    /// The interpolation always follows the shorter arc: if the quaternions
+    /// are more than a half turn apart, `other` is negated first. This
+    /// represents the same rotation.
+    ///
     /// # Performance notes
     ///
     /// The `acos` operation used in `slerp` is an expensive operation, so
@@ -238,12 +363,16 @@ impl<S: BaseFloat> Quaternion<S> {
     pub fn slerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
         use std::num::cast;
 
-        let dot = self.dot(other);
+        // take the shorter arc
+        let (dot, other) = match self.dot(other) {
+            dot if dot < zero() => (-dot, -*other),
+            dot => (dot, *other),
+        };
         let dot_threshold = cast(0.9995f64).unwrap();
 
         // if quaternions are close together use `nlerp`
         if dot > dot_threshold {
-            self.nlerp(other, amount)
+            self.nlerp(&other, amount)
         } else {
             // stay within the domain of acos()
             // TODO REMOVE WHEN https://github.com/mozilla/rust/issues/12068 IS RESOLVED
@@ -265,45 +394,6 @@ impl<S: BaseFloat> Quaternion<S> {
                 .mul_s(sin(theta).recip())
         }
     }
-
-    /// Convert a Quaternion to Eular angles
-    ///     This is a polar singularity aware conversion
-    ///
-    ///  Based on:
-    /// - [Maths - Conversion Quaternion to Euler]
-    ///   (http://www.euclideanspace.com/maths/geometry/rotations/conversions/quaternionToEuler/)
-    pub fn to_euler(&self) -> (Rad<S>, Rad<S>, Rad<S>) {
-        let sig: S = cast(0.499f64).unwrap();
-        let two: S = cast(2f64).unwrap();
-        let one: S = cast(1f64).unwrap();
-
-        let (qw, qx, qy, qz) = (self.s, self.v.x, self.v.y, self.v.z);
-        let (sqw, sqx, sqy, sqz) = (qw*qw, qx*qx, qy*qy, qz*qz);
-
-        let unit = sqx + sqy + sqz + sqw;
-        let test = qx*qy + qz*qw;
-
-        if test > sig * unit {
-            (
-                rad(zero::<S>()),
-                rad(cast(f64::consts::FRAC_PI_2).unwrap()),
-                rad(two * qx.atan2(qw)),
-            )
-        } else if test < -sig * unit {
-            let y: S = cast(f64::consts::FRAC_PI_2).unwrap();
-            (
-                rad(zero::<S>()),
-                rad(-y),
-                rad(two * qx.atan2(qw)),
-            )
-        } else {
-            (
-                rad((two * (qy*qw - qx*qz)).atan2(one - two*(sqy + sqz))),
-                rad((two * (qx*qy + qz*qw)).asin()),
-                rad((two * (qx*qw - qy*qz)).atan2(one - two*(sqx + sqz))),
-            )
-        }
-    }
 }
 
 impl<S: BaseFloat> ToMatrix3<S> for Quaternion<S> {
@@ -397,9 +487,7 @@ impl<S: BaseFloat + 'static> Rotation<S, Vector3<S>, Point3<S>> for Quaternion<S
 
     #[inline]
     fn between_vectors(a: &Vector3<S>, b: &Vector3<S>) -> Quaternion<S> {
-        //http://stackoverflow.com/questions/1171849/
-        //finding-quaternion-representing-the-rotation-from-one-vector-to-another
-        Quaternion::from_sv(one::<S>() + a.dot(b), a.cross(b)).normalize()
+        Quaternion::from_rotation_between(a, b)
     }
 
     #[inline]
@@ -419,22 +507,29 @@ impl<S: BaseFloat + 'static> Rotation<S, Vector3<S>, Point3<S>> for Quaternion<S
 }
 
 impl<S: BaseFloat> Rotation3<S> for Quaternion<S> where S: 'static {
+    /// Create a rotation of `angle` around `axis`. The axis does not need to
+    /// be normalized.
     #[inline]
     fn from_axis_angle(axis: &Vector3<S>, angle: Rad<S>) -> Quaternion<S> {
         let (s, c) = sin_cos(angle.mul_s(cast(0.5f64).unwrap()));
-        Quaternion::from_sv(c, axis.mul_s(s))
+        Quaternion::from_sv(c, axis.normalize().mul_s(s))
     }
 
-    /// - [Maths - Conversion Euler to Quaternion]
-    ///   (http://www.euclideanspace.com/maths/geometry/rotations/conversions/eulerToQuaternion/index.htm)
+    /// Create a rotation from euler angles, applied about the fixed `x`, then
+    /// `y`, then `z` axes, as in `Matrix3::from_euler`. This is the product
+    /// `from_angle_z(z) * from_angle_y(y) * from_angle_x(x)`.
+    ///
+    /// Note that this differs from earlier versions, which took the angles
+    /// as heading, attitude and bank and so gave a different rotation for
+    /// the same arguments.
     fn from_euler(x: Rad<S>, y: Rad<S>, z: Rad<S>) -> Quaternion<S> {
-        let (s1, c1) = sin_cos(x.mul_s(cast(0.5f64).unwrap()));
-        let (s2, c2) = sin_cos(y.mul_s(cast(0.5f64).unwrap()));
-        let (s3, c3) = sin_cos(z.mul_s(cast(0.5f64).unwrap()));
-
-        Quaternion::new(c1 * c2 * c3 - s1 * s2 * s3,
-                        s1 * s2 * c3 + c1 * c2 * s3,
-                        s1 * c2 * c3 + c1 * s2 * s3,
-                        c1 * s2 * c3 - s1 * c2 * s3)
+        let (sx, cx) = sin_cos(x.mul_s(cast(0.5f64).unwrap()));
+        let (sy, cy) = sin_cos(y.mul_s(cast(0.5f64).unwrap()));
+        let (sz, cz) = sin_cos(z.mul_s(cast(0.5f64).unwrap()));
+
+        Quaternion::new(cx * cy * cz + sx * sy * sz,
+                        sx * cy * cz - cx * sy * sz,
+                        cx * sy * cz + sx * cy * sz,
+                        cx * cy * sz - sx * sy * cz)
     }
 }
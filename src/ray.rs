@@ -13,9 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use num::BaseNum;
+use num::{BaseNum, BaseFloat};
 use point::{Point, Point2, Point3};
-use vector::{Vector, Vector2, Vector3};
+use vector::{Vector, EuclideanVector, Vector2, Vector3};
 
 /// A generic ray starting at `origin` and extending infinitely in
 /// `direction`.
@@ -29,6 +29,20 @@ impl<S: BaseNum, V: Vector<S>, P: Point<S, V>> Ray<P, V> {
     pub fn new(origin: P, direction: V) -> Ray<P,V> {
         Ray { origin: origin, direction: direction }
     }
+
+    /// Evaluate the point `t` lengths of `direction` along the ray.
+    #[inline]
+    pub fn at(&self, t: S) -> P {
+        self.origin.add_v(&self.direction.mul_s(t))
+    }
+}
+
+impl<S: BaseFloat, V: EuclideanVector<S>, P: Point<S, V>> Ray<P, V> {
+    /// Construct a ray with its direction normalized, so that `at(t)` is `t`
+    /// units from the origin.
+    pub fn new_normalized(origin: P, direction: V) -> Ray<P,V> {
+        Ray { origin: origin, direction: direction.normalize() }
+    }
 }
 
 pub type Ray2<S> = Ray<Point2<S>, Vector2<S>>;
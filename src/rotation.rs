@@ -227,6 +227,16 @@ impl<S: BaseFloat> ApproxEq<S> for Basis2<S> {
     fn approx_eq_eps(&self, other: &Basis2<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Basis2<S>, max_relative: &S) -> bool {
+        self.mat.relative_eq(&other.mat, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Basis2<S>, max_ulps: u32) -> bool {
+        self.mat.ulps_eq(&other.mat, max_ulps)
+    }
 }
 
 impl<S: BaseFloat + 'static> Rotation2<S> for Basis2<S> {
@@ -317,6 +327,16 @@ impl<S: BaseFloat> ApproxEq<S> for Basis3<S> {
     fn approx_eq_eps(&self, other: &Basis3<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Basis3<S>, max_relative: &S) -> bool {
+        self.mat.relative_eq(&other.mat, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Basis3<S>, max_ulps: u32) -> bool {
+        self.mat.ulps_eq(&other.mat, max_ulps)
+    }
 }
 
 impl<S: BaseFloat + 'static> Rotation3<S> for Basis3<S> {
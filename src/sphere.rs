@@ -27,18 +27,40 @@ pub struct Sphere<S> {
     pub radius: S,
 }
 
+impl<S: BaseFloat> Sphere<S> {
+    /// Test whether the point `p` lies inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains(&self, p: &Point3<S>) -> bool {
+        let l = p.sub_p(&self.center);
+        l.dot(&l) <= self.radius * self.radius
+    }
+
+    /// Find the smallest non-negative parameter `t` at which `ray` hits the
+    /// surface of the sphere. If the ray starts inside the sphere this is the
+    /// point where it leaves. Returns `None` if the ray misses.
+    pub fn intersect_ray(&self, ray: &Ray3<S>) -> Option<S> {
+        // solve |o + t*d - c|^2 = r^2 for t
+        let l = ray.origin.sub_p(&self.center);
+        let a = ray.direction.dot(&ray.direction);
+        let b = ray.direction.dot(&l);
+        let c = l.dot(&l) - self.radius * self.radius;
+
+        let disc = b * b - a * c;
+        if disc < zero() { return None; }
+
+        let sq = disc.sqrt();
+        let t0 = (-b - sq) / a;
+        let t1 = (-b + sq) / a;
+        if t0 >= zero() { Some(t0) }
+        else if t1 >= zero() { Some(t1) }
+        else { None }
+    }
+}
+
 impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Sphere<S>, Ray3<S>) {
     fn intersection(&self) -> Option<Point3<S>> {
         match *self {
-            (ref s, ref r) => {
-                let l = s.center.sub_p(&r.origin);
-                let tca = l.dot(&r.direction);
-                if tca < zero() { return None; }
-                let d2 = l.dot(&l) - tca*tca;
-                if d2 > s.radius*s.radius { return None; }
-                let thc = (s.radius*s.radius - d2).sqrt();
-                Some(r.origin.add_v(&r.direction.mul_s(tca - thc)))
-            }
+            (ref s, ref r) => s.intersect_ray(r).map(|t| r.at(t)),
         }
     }
 }
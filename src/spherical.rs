@@ -0,0 +1,108 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spherical coordinates
+
+use std::fmt;
+
+use angle::{Rad, acos, atan2, sin_cos, rad};
+use approx::ApproxEq;
+use num::{BaseFloat, zero, one};
+use vector::{Vector3, EuclideanVector};
+
+/// A point in 3-dimensional space described by its distance from the origin
+/// and two angles.
+///
+/// # Fields
+///
+/// - `radius`: the distance from the origin
+/// - `theta`: the polar angle, measured from the positive `z` axis, in the
+///   range `[0, pi]`
+/// - `phi`: the azimuthal angle, measured in the `xy` plane from the positive
+///   `x` axis towards the positive `y` axis, in the range `[-pi, pi]`
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Spherical<S> {
+    pub radius: S,
+    pub theta: Rad<S>,
+    pub phi: Rad<S>,
+}
+
+impl<S: BaseFloat> Spherical<S> {
+    #[inline]
+    pub fn new(radius: S, theta: Rad<S>, phi: Rad<S>) -> Spherical<S> {
+        Spherical { radius: radius, theta: theta, phi: phi }
+    }
+
+    /// Convert a cartesian vector to spherical coordinates.
+    ///
+    /// Points on the `z` axis have an azimuth of zero, and the origin has a
+    /// polar angle of zero.
+    pub fn from_vec3(v: &Vector3<S>) -> Spherical<S> {
+        let radius = v.length();
+        let theta = if radius == zero() {
+            rad(zero())
+        } else {
+            // guard against rounding pushing the cosine out of range
+            acos((v.z / radius).partial_max(-one::<S>()).partial_min(one()))
+        };
+        Spherical::new(radius, theta, atan2(v.y, v.x))
+    }
+
+    /// Convert to a cartesian vector.
+    pub fn to_vec3(&self) -> Vector3<S> {
+        let (sin_theta, cos_theta) = sin_cos(self.theta);
+        let (sin_phi, cos_phi) = sin_cos(self.phi);
+        Vector3::new(self.radius * sin_theta * cos_phi,
+                     self.radius * sin_theta * sin_phi,
+                     self.radius * cos_theta)
+    }
+}
+
+impl<S: BaseFloat> Vector3<S> {
+    /// Convert this vector to spherical coordinates.
+    #[inline]
+    pub fn to_spherical(&self) -> Spherical<S> {
+        Spherical::from_vec3(self)
+    }
+}
+
+impl<S: BaseFloat> ApproxEq<S> for Spherical<S> {
+    #[inline]
+    fn approx_eq_eps(&self, other: &Spherical<S>, epsilon: &S) -> bool {
+        self.radius.approx_eq_eps(&other.radius, epsilon) &&
+        self.theta.approx_eq_eps(&other.theta, epsilon) &&
+        self.phi.approx_eq_eps(&other.phi, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Spherical<S>, max_relative: &S) -> bool {
+        self.radius.relative_eq(&other.radius, max_relative) &&
+        self.theta.relative_eq(&other.theta, max_relative) &&
+        self.phi.relative_eq(&other.phi, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Spherical<S>, max_ulps: u32) -> bool {
+        self.radius.ulps_eq(&other.radius, max_ulps) &&
+        self.theta.ulps_eq(&other.theta, max_ulps) &&
+        self.phi.ulps_eq(&other.phi, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Show for Spherical<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.radius, self.theta, self.phi)
+    }
+}
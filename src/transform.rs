@@ -15,13 +15,14 @@
 
 use std::fmt;
 
+use angle::Rad;
 use approx::ApproxEq;
-use matrix::{Matrix, Matrix4, ToMatrix4};
+use matrix::{Matrix, Matrix2, Matrix3, Matrix4, ToMatrix3, ToMatrix4};
 use num::{BaseNum, BaseFloat, zero, one};
-use point::{Point, Point3};
+use point::{Point, Point2, Point3};
 use ray::Ray;
-use rotation::{Rotation, Rotation3};
-use vector::{Vector, Vector3};
+use rotation::{Rotation, Rotation2, Rotation3};
+use vector::{Vector, Vector2, Vector3};
 
 /// A trait representing an [affine
 /// transformation](https://en.wikipedia.org/wiki/Affine_transformation) that
@@ -139,8 +140,20 @@ impl<S: BaseFloat, V: Vector<S>, P: Point<S, V>, R: Rotation<S, V, P>> Transform
     }
 }
 
+pub trait Transform2<S>: Transform<S, Vector2<S>, Point2<S>> + ToMatrix3<S> {}
+
 pub trait Transform3<S>: Transform<S, Vector3<S>, Point3<S>>+ ToMatrix4<S> {}
 
+impl<S: BaseFloat + 'static, R: Rotation2<S>> ToMatrix3<S> for Decomposed<S, Vector2<S>, R> {
+    fn to_matrix3(&self) -> Matrix3<S> {
+        let mut m = self.rot.to_matrix2().mul_s(self.scale.clone()).to_matrix3();
+        m.z = self.disp.extend(one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<S>> Transform2<S> for Decomposed<S,Vector2<S>,R> where S: 'static {}
+
 impl<S: BaseFloat + 'static, R: Rotation3<S>> ToMatrix4<S> for Decomposed<S, Vector3<S>, R> {
     fn to_matrix4(&self) -> Matrix4<S> {
         let mut m = self.rot.to_matrix3().mul_s(self.scale.clone()).to_matrix4();
@@ -158,6 +171,75 @@ impl<S: BaseFloat, R: fmt::Show + Rotation3<S>> fmt::Show for Decomposed<S,Vecto
     }
 }
 
+/// A homogeneous transformation matrix in two dimensions.
+#[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
+pub struct AffineMatrix2<S> {
+    pub mat: Matrix3<S>,
+}
+
+impl<S: BaseFloat + 'static> AffineMatrix2<S> {
+    /// Create a transformation that translates by `v`.
+    #[inline]
+    pub fn from_translation(v: &Vector2<S>) -> AffineMatrix2<S> {
+        let mut mat = Matrix3::identity();
+        mat.z = v.extend(one());
+        AffineMatrix2 { mat: mat }
+    }
+
+    /// Create a transformation that rotates counterclockwise by `theta`.
+    #[inline]
+    pub fn from_angle(theta: Rad<S>) -> AffineMatrix2<S> {
+        AffineMatrix2 { mat: Matrix2::from_angle(theta).to_matrix3() }
+    }
+
+    /// Create a transformation that scales uniformly by `value`.
+    #[inline]
+    pub fn from_scale(value: S) -> AffineMatrix2<S> {
+        AffineMatrix2 { mat: Matrix3::from_diagonal(&Vector3::new(value, value, one())) }
+    }
+}
+
+impl<S: BaseFloat + 'static> Transform<S, Vector2<S>, Point2<S>> for AffineMatrix2<S> {
+    #[inline]
+    fn identity() -> AffineMatrix2<S> {
+       AffineMatrix2 { mat: Matrix3::identity() }
+    }
+
+    #[inline]
+    fn look_at(eye: &Point2<S>, center: &Point2<S>, up: &Vector2<S>) -> AffineMatrix2<S> {
+        let rot = Matrix2::look_at(&center.sub_p(eye), up);
+        let mut mat = rot.to_matrix3();
+        mat.z = (-rot.mul_v(&eye.to_vec())).extend(one());
+        AffineMatrix2 { mat: mat }
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: &Vector2<S>) -> Vector2<S> {
+        self.mat.mul_v(&vec.extend(zero())).truncate()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        self.mat.transform_point(point)
+    }
+
+    #[inline]
+    fn concat(&self, other: &AffineMatrix2<S>) -> AffineMatrix2<S> {
+        AffineMatrix2 { mat: self.mat.mul_m(&other.mat) }
+    }
+
+    #[inline]
+    fn invert(&self) -> Option<AffineMatrix2<S>> {
+        self.mat.invert().map(|m| AffineMatrix2{ mat: m })
+    }
+}
+
+impl<S: BaseNum> ToMatrix3<S> for AffineMatrix2<S> {
+    #[inline] fn to_matrix3(&self) -> Matrix3<S> { self.mat.clone() }
+}
+
+impl<S: BaseFloat> Transform2<S> for AffineMatrix2<S> where S: 'static {}
+
 /// A homogeneous transformation matrix.
 #[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
 pub struct AffineMatrix3<S> {
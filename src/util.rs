@@ -0,0 +1,67 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scalar helper functions.
+
+use std::num::cast;
+
+use num::{BaseFloat, PartialOrd, zero, one};
+
+/// Clamps `x` to the range `[lo, hi]`.
+#[inline]
+pub fn clamp<S: PartialOrd>(x: S, lo: S, hi: S) -> S {
+    x.partial_max(lo).partial_min(hi)
+}
+
+/// Linearly interpolates from `a` to `b` by `t`, where a `t` of `0` gives `a`
+/// and a `t` of `1` gives `b`.
+#[inline]
+pub fn lerp<S: BaseFloat>(a: S, b: S, t: S) -> S {
+    a + (b - a) * t
+}
+
+/// Returns `0` if `x` is less than `edge`, and `1` otherwise.
+#[inline]
+pub fn step<S: BaseFloat>(edge: S, x: S) -> S {
+    if x < edge { zero() } else { one() }
+}
+
+/// Maps `x` onto the range `[0, 1]` relative to the edges, clamping values
+/// outside of them.
+#[inline]
+fn unit_ramp<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    clamp((x - edge0) / (edge1 - edge0), zero(), one())
+}
+
+/// Performs smooth Hermite interpolation between `0` and `1` as `x` moves from
+/// `edge0` to `edge1`, returning `0` below `edge0` and `1` above `edge1`.
+#[inline]
+pub fn smoothstep<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    let t = unit_ramp(edge0, edge1, x);
+    let two: S = cast(2i).unwrap();
+    let three: S = cast(3i).unwrap();
+    t * t * (three - two * t)
+}
+
+/// A variant of `smoothstep` using a quintic polynomial, which also has zero
+/// second derivatives at the edges.
+#[inline]
+pub fn smootherstep<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    let t = unit_ramp(edge0, edge1, x);
+    let six: S = cast(6i).unwrap();
+    let ten: S = cast(10i).unwrap();
+    let fifteen: S = cast(15i).unwrap();
+    t * t * t * (t * (t * six - fifteen) + ten)
+}
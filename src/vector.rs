@@ -97,19 +97,26 @@
 //! (../array/trait.EuclideanVector.html) is more specific to Euclidean space.
 
 use std::fmt;
+use std::iter::FromIterator;
 use std::mem;
-use std::num::NumCast;
+use std::num::{Int, NumCast, cast};
 use std::ops::*;
+use std::rand::{Rand, Rng};
 
-use angle::{Rad, atan2, acos};
+use angle::{Angle, Rad, atan2, acos, sin_cos};
 use approx::ApproxEq;
 use array::{Array1, FixedArray};
+use bivector::Bivector3;
+use interpolate::Lerp;
 use num::{BaseNum, BaseFloat, Zero, One, zero, one};
 
 /// A trait that specifies a range of numeric operations for vectors. Not all
 /// of these make sense from a linear algebra point of view, but are included
 /// for pragmatic reasons.
-pub trait Vector<S: BaseNum>: Array1<S> + Zero + One + Neg<Self> {
+///
+/// Every vector can be linearly interpolated, so `Lerp` is a supertrait and
+/// `lerp` is available to code that is generic over `Vector`.
+pub trait Vector<S: BaseNum>: Array1<S> + Zero + One + Neg<Self> + Lerp<S> {
     /// Add a scalar to this vector, returning a new vector.
     fn add_s(&self, s: S) -> Self;
     /// Subtract a scalar from this vector, returning a new vector.
@@ -166,19 +173,116 @@ pub trait Vector<S: BaseNum>: Array1<S> + Zero + One + Neg<Self> {
     #[inline]
     fn dot(&self, v: &Self) -> S { self.mul_v(v).comp_add() }
 
+    /// The squared magnitude of the vector, which is its dot product with
+    /// itself. Unlike `EuclideanVector::magnitude` this needs no square root,
+    /// so it is cheaper for comparing lengths and available for integer
+    /// vectors.
+    #[inline]
+    fn magnitude2(&self) -> S { self.dot(self) }
+
     /// The minimum component of the vector.
     fn comp_min(&self) -> S;
     /// The maximum component of the vector.
     fn comp_max(&self) -> S;
+
+    /// The component-wise minimum of this vector and another.
+    fn min(&self, v: &Self) -> Self;
+    /// The component-wise maximum of this vector and another.
+    fn max(&self, v: &Self) -> Self;
+
+    /// Clamp each component of the vector between the corresponding
+    /// components of `lo` and `hi`.
+    #[inline]
+    fn clamp(&self, lo: &Self, hi: &Self) -> Self { self.max(lo).min(hi) }
+
+    /// Linearly interpolates each component of the vector towards `other` by
+    /// the specified amount, in-place.
+    #[inline]
+    fn lerp_self(&mut self, other: &Self, amount: S) {
+        let v = other.sub_v(self).mul_s(amount);
+        self.add_self_v(&v);
+    }
+}
+
+/// Vectors that have a cross product, allowing generic code to be written
+/// over three-dimensional vectors.
+pub trait Cross<S: BaseNum>: Vector<S> {
+    /// Returns the cross product of the vector and `other`.
+    fn cross(&self, other: &Self) -> Self;
 }
 
 /// Dot product of two vectors.
 #[inline] pub fn dot<S: BaseNum, V: Vector<S>>(a: V, b: V) -> S { a.dot(&b) }
 
+/// The point halfway between `a` and `b`.
+#[inline]
+pub fn midpoint<S: BaseFloat, V: Vector<S>>(a: &V, b: &V) -> V {
+    a.add_v(b).div_s(one::<S>() + one())
+}
+
+/// The average of a set of points. An empty slice has no centroid, so the
+/// zero vector is returned.
+pub fn centroid<S: BaseFloat, V: Vector<S>>(points: &[V]) -> V {
+    if points.is_empty() { return zero(); }
+    let sum = points.iter().fold(zero::<V>(), |acc, p| acc.add_v(p));
+    sum.div_s(cast(points.len()).unwrap())
+}
+
+/// The barycentric coordinates of `p` with respect to the triangle `abc`.
+/// These are the weights `(u, v, w)` for which `p` is `u * a + v * b + w * c`,
+/// and they sum to one. `p` is inside the triangle when all three are
+/// non-negative.
+///
+/// Returns `None` if the triangle has zero area.
+pub fn barycentric<S: BaseFloat>(p: &Vector2<S>, a: &Vector2<S>, b: &Vector2<S>,
+                                 c: &Vector2<S>) -> Option<(S, S, S)> {
+    let (ab, ac, ap) = (b.sub_v(a), c.sub_v(a), p.sub_v(a));
+    let area = ab.perp_dot(&ac);
+    if area == zero() { return None; }
+    let v = ap.perp_dot(&ac) / area;
+    let w = ab.perp_dot(&ap) / area;
+    Some((one::<S>() - v - w, v, w))
+}
+
+/// The barycentric coordinates of `p` with respect to the triangle `abc` in
+/// three dimensions. If `p` is not in the plane of the triangle, the
+/// coordinates of its projection onto that plane are returned.
+///
+/// Returns `None` if the triangle has zero area.
+pub fn barycentric3<S: BaseFloat>(p: &Vector3<S>, a: &Vector3<S>, b: &Vector3<S>,
+                                  c: &Vector3<S>) -> Option<(S, S, S)> {
+    let (ab, ac, ap) = (b.sub_v(a), c.sub_v(a), p.sub_v(a));
+    let (d00, d01, d11) = (ab.dot(&ab), ab.dot(&ac), ac.dot(&ac));
+    let (d20, d21) = (ap.dot(&ab), ap.dot(&ac));
+    let denom = d00 * d11 - d01 * d01;
+    if denom == zero() { return None; }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some((one::<S>() - v - w, v, w))
+}
+
+/// An arbitrary unit vector perpendicular to `v`, found by crossing it with
+/// the axis it is least aligned with. `v` must not be zero.
+pub fn perpendicular<S: BaseFloat>(v: &Vector3<S>) -> Vector3<S> {
+    let (x, y, z) = (v.x.abs(), v.y.abs(), v.z.abs());
+    let axis = if x <= y && x <= z {
+        Vector3::unit_x()
+    } else if y <= z {
+        Vector3::unit_y()
+    } else {
+        Vector3::unit_z()
+    };
+    v.cross(&axis).normalize()
+}
+
 // Utility macro for generating associated functions for the vectors
 macro_rules! vec(
     ($Self:ident <$S:ident> { $($field:ident),+ }, $n:expr) => (
-        #[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable, Rand)]
+        // Vectors are ordered lexicographically, comparing `x`, then `y`, and
+        // so on. Only vectors of totally ordered scalars, such as integers,
+        // implement `Ord`.
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable, Rand)]
+        #[repr(C)]
         pub struct $Self<S> { $(pub $field: S),+ }
 
         impl<$S> $Self<$S> {
@@ -195,6 +299,93 @@ macro_rules! vec(
             pub fn from_value(value: $S) -> $Self<$S> {
                 $Self { $($field: value),+ }
             }
+
+            /// Construct a vector from an array of its components.
+            #[inline]
+            pub fn from_array(a: [$S; $n]) -> $Self<$S> {
+                *FixedArray::from_fixed_ref(&a)
+            }
+
+            /// Copy the components of the vector into an array.
+            #[inline]
+            pub fn to_array(&self) -> [$S; $n] {
+                *self.as_fixed()
+            }
+        }
+
+        impl<$S: BaseNum> $Self<$S> {
+            /// The zero vector (with all components set to zero).
+            #[inline]
+            pub fn zero() -> $Self<$S> { $Self { $($field: zero()),+ } }
+
+            /// A vector with all components set to one.
+            #[inline]
+            pub fn one() -> $Self<$S> { $Self { $($field: one()),+ } }
+        }
+
+        impl<$S: Int> $Self<$S> {
+            /// Component-wise addition, saturating at the numeric bounds
+            /// instead of overflowing.
+            #[inline]
+            pub fn saturating_add_v(&self, v: &$Self<$S>) -> $Self<$S> {
+                $Self { $($field: self.$field.saturating_add(v.$field)),+ }
+            }
+
+            /// Component-wise subtraction, saturating at the numeric bounds
+            /// instead of overflowing.
+            #[inline]
+            pub fn saturating_sub_v(&self, v: &$Self<$S>) -> $Self<$S> {
+                $Self { $($field: self.$field.saturating_sub(v.$field)),+ }
+            }
+
+            /// Component-wise addition, returning `None` if any component
+            /// overflows.
+            #[inline]
+            pub fn checked_add_v(&self, v: &$Self<$S>) -> Option<$Self<$S>> {
+                match ($(self.$field.checked_add(v.$field)),+) {
+                    ($(Some($field)),+) => Some($Self { $($field: $field),+ }),
+                    _ => None,
+                }
+            }
+
+            /// Component-wise subtraction, returning `None` if any component
+            /// overflows.
+            #[inline]
+            pub fn checked_sub_v(&self, v: &$Self<$S>) -> Option<$Self<$S>> {
+                match ($(self.$field.checked_sub(v.$field)),+) {
+                    ($(Some($field)),+) => Some($Self { $($field: $field),+ }),
+                    _ => None,
+                }
+            }
+
+            /// Component-wise multiplication, returning `None` if any
+            /// component overflows.
+            #[inline]
+            pub fn checked_mul_v(&self, v: &$Self<$S>) -> Option<$Self<$S>> {
+                match ($(self.$field.checked_mul(v.$field)),+) {
+                    ($(Some($field)),+) => Some($Self { $($field: $field),+ }),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<$S: BaseFloat> $Self<$S> {
+            /// The absolute value of each component.
+            #[inline]
+            pub fn abs(&self) -> $Self<$S> { $Self { $($field: self.$field.abs()),+ } }
+
+            /// Each component rounded down to the nearest integer.
+            #[inline]
+            pub fn floor(&self) -> $Self<$S> { $Self { $($field: self.$field.floor()),+ } }
+
+            /// Each component rounded up to the nearest integer.
+            #[inline]
+            pub fn ceil(&self) -> $Self<$S> { $Self { $($field: self.$field.ceil()),+ } }
+
+            /// Each component rounded to the nearest integer, with halfway
+            /// cases rounded away from zero.
+            #[inline]
+            pub fn round(&self) -> $Self<$S> { $Self { $($field: self.$field.round()),+ } }
         }
 
         impl<$S: Zero> Zero for $Self<$S> {
@@ -210,6 +401,29 @@ macro_rules! vec(
             fn one() -> $Self<$S> { $Self { $($field: one()),+ } }
         }
 
+        impl<$S: Zero> Default for $Self<$S> {
+            /// The zero vector.
+            #[inline]
+            fn default() -> $Self<$S> { $Self { $($field: zero()),+ } }
+        }
+
+        impl<$S: Copy> $Self<$S> {
+            /// Combine the components of this vector with the corresponding
+            /// components of `other`, using the provided function.
+            #[inline]
+            pub fn zip_with(&self, other: &$Self<$S>, op: |$S, $S| -> $S) -> $Self<$S> {
+                $Self { $($field: op(self.$field, other.$field)),+ }
+            }
+
+            /// Accumulate the components of the vector, in order.
+            #[inline]
+            pub fn fold<B>(&self, init: B, op: |B, &$S| -> B) -> B {
+                let mut acc = init;
+                $(acc = op(acc, &self.$field);)+
+                acc
+            }
+        }
+
         impl<$S: NumCast + Copy> $Self<$S> {
             /// Component-wise casting to another type
             #[inline]
@@ -218,6 +432,15 @@ macro_rules! vec(
             }
         }
 
+        impl<$S> FromIterator<$S> for $Self<$S> {
+            /// Construct a vector from the first elements of an iterator,
+            /// panicking if there are too few.
+            #[inline]
+            fn from_iter<I: Iterator<$S>>(mut iter: I) -> $Self<$S> {
+                $Self { $($field: iter.next().expect("Not enough elements to construct a vector")),+ }
+            }
+        }
+
         impl<$S> FixedArray<[$S; $n]> for $Self<$S> {
             #[inline]
             fn into_fixed(self) -> [$S; $n] {
@@ -303,6 +526,9 @@ macro_rules! vec(
             #[inline] fn comp_mul(&self) -> S { fold!(mul, { $(self.$field),+ }) }
             #[inline] fn comp_min(&self) -> S { fold!(partial_min, { $(self.$field),+ }) }
             #[inline] fn comp_max(&self) -> S { fold!(partial_max, { $(self.$field),+ }) }
+
+            #[inline] fn min(&self, v: &$Self<S>) -> $Self<S> { $Self::new($(self.$field.partial_min(v.$field)),+) }
+            #[inline] fn max(&self, v: &$Self<S>) -> $Self<S> { $Self::new($(self.$field.partial_max(v.$field)),+) }
         }
 
         impl<S: BaseNum> Add<$Self<S>, $Self<S>> for $Self<S> {
@@ -321,6 +547,10 @@ macro_rules! vec(
             #[inline] fn mul(self, v: $Self<S>) -> $Self<S> { self.mul_v(&v) }
         }
 
+        impl<S: BaseNum> Mul<S, $Self<S>> for $Self<S> {
+            #[inline] fn mul(self, s: S) -> $Self<S> { self.mul_s(s) }
+        }
+
         impl<S: BaseNum> Div<$Self<S>, $Self<S>> for $Self<S> {
             #[inline] fn div(self, v: $Self<S>) -> $Self<S> { self.div_v(&v) }
         }
@@ -334,6 +564,16 @@ macro_rules! vec(
             fn approx_eq_eps(&self, other: &$Self<S>, epsilon: &S) -> bool {
                 $(self.$field.approx_eq_eps(&other.$field, epsilon))&&+
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$Self<S>, max_relative: &S) -> bool {
+                $(self.$field.relative_eq(&other.$field, max_relative))&&+
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Self<S>, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, max_ulps))&&+
+            }
         }
     )
 );
@@ -351,19 +591,109 @@ vec!(Vector2<S> { x, y }, 2);
 vec!(Vector3<S> { x, y, z }, 3);
 vec!(Vector4<S> { x, y, z, w }, 4);
 
+// Utility macro for generating GLSL-style swizzle accessors, each returning a
+// new vector built from the listed components.
+macro_rules! swizzle(
+    ($Self:ident { $($name:ident => $Out:ident($($field:ident),+)),+ }) => (
+        impl<S: Copy> $Self<S> {
+            $(#[inline] pub fn $name(&self) -> $Out<S> { $Out::new($(self.$field),+) })+
+        }
+    )
+);
+
+swizzle!(Vector2 {
+    xy => Vector2(x, y),
+    yx => Vector2(y, x)
+});
+
+swizzle!(Vector3 {
+    xy => Vector2(x, y),
+    xz => Vector2(x, z),
+    yx => Vector2(y, x),
+    yz => Vector2(y, z),
+    zx => Vector2(z, x),
+    zy => Vector2(z, y),
+    xyz => Vector3(x, y, z),
+    xzy => Vector3(x, z, y),
+    yxz => Vector3(y, x, z),
+    yzx => Vector3(y, z, x),
+    zxy => Vector3(z, x, y),
+    zyx => Vector3(z, y, x)
+});
+
+swizzle!(Vector4 {
+    xy => Vector2(x, y),
+    xz => Vector2(x, z),
+    xw => Vector2(x, w),
+    yx => Vector2(y, x),
+    yz => Vector2(y, z),
+    yw => Vector2(y, w),
+    zx => Vector2(z, x),
+    zy => Vector2(z, y),
+    zw => Vector2(z, w),
+    wx => Vector2(w, x),
+    wy => Vector2(w, y),
+    wz => Vector2(w, z),
+    xyz => Vector3(x, y, z),
+    xyw => Vector3(x, y, w),
+    xzy => Vector3(x, z, y),
+    xzw => Vector3(x, z, w),
+    xwy => Vector3(x, w, y),
+    xwz => Vector3(x, w, z),
+    yxz => Vector3(y, x, z),
+    yxw => Vector3(y, x, w),
+    yzx => Vector3(y, z, x),
+    yzw => Vector3(y, z, w),
+    ywx => Vector3(y, w, x),
+    ywz => Vector3(y, w, z),
+    zxy => Vector3(z, x, y),
+    zxw => Vector3(z, x, w),
+    zyx => Vector3(z, y, x),
+    zyw => Vector3(z, y, w),
+    zwx => Vector3(z, w, x),
+    zwy => Vector3(z, w, y),
+    wxy => Vector3(w, x, y),
+    wxz => Vector3(w, x, z),
+    wyx => Vector3(w, y, x),
+    wyz => Vector3(w, y, z),
+    wzx => Vector3(w, z, x),
+    wzy => Vector3(w, z, y)
+});
+
 /// Operations specific to numeric two-dimensional vectors.
 impl<S: BaseNum> Vector2<S> {
+    /// Construct a vector from a tuple of its components.
+    #[inline]
+    pub fn from_tuple(t: (S, S)) -> Vector2<S> {
+        let (x, y) = t;
+        Vector2::new(x, y)
+    }
+
+    /// Convert the vector into a tuple of its components, for destructuring
+    /// with `let (x, y) = v.into_tuple();`.
+    #[inline]
+    pub fn into_tuple(self) -> (S, S) { (self.x, self.y) }
+
     /// A unit vector in the `x` direction.
     #[inline] pub fn unit_x() -> Vector2<S> { Vector2::new(one(), zero()) }
     /// A unit vector in the `y` direction.
     #[inline] pub fn unit_y() -> Vector2<S> { Vector2::new(zero(), one()) }
 
-    /// The perpendicular dot product of the vector and `other`.
+    /// The perpendicular dot product of the vector and `other`. This is the
+    /// `z` component of the cross product of the two vectors extended into
+    /// three dimensions, so it is positive when `other` is counterclockwise
+    /// from the vector and zero when they are parallel.
     #[inline]
     pub fn perp_dot(&self, other: &Vector2<S>) -> S {
         (self.x * other.y) - (self.y * other.x)
     }
 
+    /// The vector rotated counterclockwise by 90 degrees.
+    #[inline]
+    pub fn perp(&self) -> Vector2<S> {
+        Vector2::new(-self.y, self.x)
+    }
+
     /// Create a `Vector3`, using the `x` and `y` values from this vector, and the
     /// provided `z`.
     #[inline]
@@ -372,21 +702,57 @@ impl<S: BaseNum> Vector2<S> {
     }
 }
 
+/// Operations specific to floating point two-dimensional vectors.
+impl<S: BaseFloat> Vector2<S> {
+    /// Construct a vector from polar coordinates, where `angle` is measured
+    /// from the positive `x` axis towards the positive `y` axis.
+    #[inline]
+    pub fn from_polar(radius: S, angle: Rad<S>) -> Vector2<S> {
+        let (s, c) = sin_cos(angle);
+        Vector2::new(radius * c, radius * s)
+    }
+
+    /// Convert the vector to polar coordinates, returning its length and the
+    /// angle from the positive `x` axis in the range `[-pi, pi]`. The zero
+    /// vector has an angle of zero.
+    #[inline]
+    pub fn to_polar(&self) -> (S, Rad<S>) {
+        (self.length(), atan2(self.y, self.x))
+    }
+}
+
 /// Operations specific to numeric three-dimensional vectors.
 impl<S: BaseNum> Vector3<S> {
+    /// Construct a vector from a tuple of its components.
+    #[inline]
+    pub fn from_tuple(t: (S, S, S)) -> Vector3<S> {
+        let (x, y, z) = t;
+        Vector3::new(x, y, z)
+    }
+
+    /// Convert the vector into a tuple of its components, for destructuring
+    /// with `let (x, y, z) = v.into_tuple();`.
+    #[inline]
+    pub fn into_tuple(self) -> (S, S, S) { (self.x, self.y, self.z) }
+
     /// A unit vector in the `x` direction.
     #[inline] pub fn unit_x() -> Vector3<S> { Vector3::new(one(), zero(), zero()) }
     /// A unit vector in the `y` direction.
     #[inline] pub fn unit_y() -> Vector3<S> { Vector3::new(zero(), one(), zero()) }
-    /// A unit vector in the `w` direction.
+    /// A unit vector in the `z` direction.
     #[inline] pub fn unit_z() -> Vector3<S> { Vector3::new(zero(), zero(), one()) }
 
     /// Returns the cross product of the vector and `other`.
     #[inline]
     pub fn cross(&self, other: &Vector3<S>) -> Vector3<S> {
-        Vector3::new((self.y * other.z) - (self.z * other.y),
-                     (self.z * other.x) - (self.x * other.z),
-                     (self.x * other.y) - (self.y * other.x))
+        Cross::cross(self, other)
+    }
+
+    /// Returns the wedge product of the vector and `other`, which is the
+    /// bivector spanning the plane of the two vectors.
+    #[inline]
+    pub fn wedge(&self, other: &Vector3<S>) -> Bivector3<S> {
+        Bivector3::from_wedge(self, other)
     }
 
     /// Calculates the cross product of the vector and `other`, then stores the
@@ -396,6 +762,20 @@ impl<S: BaseNum> Vector3<S> {
         *self = self.cross(other)
     }
 
+    /// The scalar triple product `self · (b × c)`, which is the signed volume
+    /// of the parallelepiped with edges `self`, `b` and `c`. It is zero when
+    /// the three vectors are coplanar.
+    #[inline]
+    pub fn triple_product(&self, b: &Vector3<S>, c: &Vector3<S>) -> S {
+        self.dot(&b.cross(c))
+    }
+
+    /// The vector triple product `self × (b × c)`.
+    #[inline]
+    pub fn vector_triple_product(&self, b: &Vector3<S>, c: &Vector3<S>) -> Vector3<S> {
+        self.cross(&b.cross(c))
+    }
+
     /// Create a `Vector4`, using the `x`, `y` and `z` values from this vector, and the
     /// provided `w`.
     #[inline]
@@ -410,8 +790,43 @@ impl<S: BaseNum> Vector3<S> {
     }
 }
 
+impl<S: BaseNum> Cross<S> for Vector3<S> {
+    #[inline]
+    fn cross(&self, other: &Vector3<S>) -> Vector3<S> {
+        Vector3::new((self.y * other.z) - (self.z * other.y),
+                     (self.z * other.x) - (self.x * other.z),
+                     (self.x * other.y) - (self.y * other.x))
+    }
+}
+
+/// Operations specific to floating point three-dimensional vectors.
+impl<S: BaseFloat + Rand> Vector3<S> {
+    /// Generate a random unit vector, uniformly distributed over the surface
+    /// of the unit sphere.
+    pub fn rand_unit<R: Rng>(rng: &mut R) -> Vector3<S> {
+        let two: S = one::<S>() + one::<S>();
+        let full_turn: Rad<S> = Angle::full_turn();
+        let z = rng.gen::<S>() * two - one();
+        let (s, c) = sin_cos(full_turn.mul_s(rng.gen::<S>()));
+        let r = (one::<S>() - z * z).sqrt();
+        Vector3::new(r * c, r * s, z)
+    }
+}
+
 /// Operations specific to numeric four-dimensional vectors.
 impl<S: BaseNum> Vector4<S> {
+    /// Construct a vector from a tuple of its components.
+    #[inline]
+    pub fn from_tuple(t: (S, S, S, S)) -> Vector4<S> {
+        let (x, y, z, w) = t;
+        Vector4::new(x, y, z, w)
+    }
+
+    /// Convert the vector into a tuple of its components, for destructuring
+    /// with `let (x, y, z, w) = v.into_tuple();`.
+    #[inline]
+    pub fn into_tuple(self) -> (S, S, S, S) { (self.x, self.y, self.z, self.w) }
+
     /// A unit vector in the `x` direction.
     #[inline] pub fn unit_x() -> Vector4<S> { Vector4::new(one(), zero(), zero(), zero()) }
     /// A unit vector in the `y` direction.
@@ -451,23 +866,43 @@ pub trait EuclideanVector<S: BaseFloat>: Vector<S>
         self.dot(other).approx_eq(&zero())
     }
 
+    /// The magnitude (or norm) of the vector.
+    #[inline]
+    fn magnitude(&self) -> S {
+        self.magnitude2().sqrt()
+    }
+
     /// Returns the squared length of the vector. This does not perform an
     /// expensive square root operation like in the `length` method and can
     /// therefore be more efficient for comparing the lengths of two vectors.
+    /// This is the same as `magnitude2`.
     #[inline]
     fn length2(&self) -> S {
-        self.dot(self)
+        self.magnitude2()
     }
 
-    /// The norm of the vector.
+    /// The norm of the vector. This is the same as `magnitude`.
     #[inline]
     fn length(&self) -> S {
-        self.dot(self).sqrt()
+        self.magnitude()
     }
 
     /// The angle between the vector and `other`, in radians.
     fn angle(&self, other: &Self) -> Rad<S>;
 
+    /// The squared Euclidean distance between the vector and `other`. This
+    /// avoids the square root in `distance`, so is cheaper for comparisons.
+    #[inline]
+    fn distance2(&self, other: &Self) -> S {
+        other.sub_v(self).length2()
+    }
+
+    /// The Euclidean distance between the vector and `other`.
+    #[inline]
+    fn distance(&self, other: &Self) -> S {
+        other.sub_v(self).length()
+    }
+
     /// Returns a vector with the same direction, but with a `length` (or
     /// `norm`) of `1`.
     #[inline]
@@ -475,38 +910,77 @@ pub trait EuclideanVector<S: BaseFloat>: Vector<S>
         self.normalize_to(one::<S>())
     }
 
-    /// Returns a vector with the same direction and a given `length`.
+    /// Returns a vector with the same direction and a given `length`. The
+    /// zero vector has no direction, so is returned unchanged.
     #[inline]
     fn normalize_to(&self, length: S) -> Self {
-        self.mul_s(length / self.length())
+        if self.is_zero() { zero() } else { self.mul_s(length / self.magnitude()) }
     }
 
-    /// Returns the result of linarly interpolating the length of the vector
-    /// towards the length of `other` by the specified amount.
+    /// Returns a vector with the same direction, but with its length clamped
+    /// to at most `max`.
     #[inline]
-    fn lerp(&self, other: &Self, amount: S) -> Self {
-        self.add_v(&other.sub_v(self).mul_s(amount))
+    fn limit_length(&self, max: S) -> Self {
+        self.normalize_to(self.length().partial_min(max))
     }
 
-    /// Normalises the vector to a length of `1`.
+    /// Normalises the vector to a length of `1`, in-place. The zero vector has
+    /// no direction, so is left unchanged.
     #[inline]
     fn normalize_self(&mut self) {
-        let rlen = self.length().recip();
-        self.mul_self_s(rlen);
+        self.normalize_self_to(one::<S>());
     }
 
-    /// Normalizes the vector to `length`.
+    /// Normalizes the vector to `length`, in-place. The zero vector is left
+    /// unchanged.
     #[inline]
     fn normalize_self_to(&mut self, length: S) {
-        let n = length / self.length();
-        self.mul_self_s(n);
+        if !self.is_zero() {
+            let n = length / self.magnitude();
+            self.mul_self_s(n);
+        }
     }
 
-    /// Linearly interpolates the length of the vector towards the length of
-    /// `other` by the specified amount.
-    fn lerp_self(&mut self, other: &Self, amount: S) {
-        let v = other.sub_v(self).mul_s(amount);
-        self.add_self_v(&v);
+    /// The component of the vector parallel to `other`. Projecting onto the
+    /// zero vector returns zero.
+    #[inline]
+    fn project_on(&self, other: &Self) -> Self {
+        if other.is_zero() {
+            zero()
+        } else {
+            other.mul_s(self.dot(other) / other.length2())
+        }
+    }
+
+    /// The component of the vector perpendicular to `other`, such that
+    /// `v.project_on(&o).add_v(&v.reject_from(&o))` is equal to `v`.
+    #[inline]
+    fn reject_from(&self, other: &Self) -> Self {
+        self.sub_v(&self.project_on(other))
+    }
+
+    /// Reflect the vector off a surface with the given `normal`, which should
+    /// be normalized.
+    #[inline]
+    fn reflect(&self, normal: &Self) -> Self {
+        let two = one::<S>() + one::<S>();
+        self.sub_v(&normal.mul_s(two * self.dot(normal)))
+    }
+
+    /// Refract the vector through a surface with the given `normal`, where
+    /// `eta` is the ratio of the refractive indices on either side of the
+    /// surface. Both the vector and `normal` should be normalized.
+    ///
+    /// Returns the zero vector in the case of total internal reflection.
+    fn refract(&self, normal: &Self, eta: S) -> Self {
+        // http://www.cs.cornell.edu/courses/cs4620/2011fa/lectures/lec14-refraction.pdf
+        let cos_i = normal.dot(self);
+        let k = one::<S>() - eta * eta * (one::<S>() - cos_i * cos_i);
+        if k < zero() {
+            zero()
+        } else {
+            self.mul_s(eta).sub_v(&normal.mul_s(eta * cos_i + k.sqrt()))
+        }
     }
 }
 
@@ -527,7 +1001,9 @@ impl<S: BaseFloat> EuclideanVector<S> for Vector3<S> {
 impl<S: BaseFloat> EuclideanVector<S> for Vector4<S> {
     #[inline]
     fn angle(&self, other: &Vector4<S>) -> Rad<S> {
-        acos(self.dot(other) / (self.length() * other.length()))
+        // rounding errors can push the cosine just outside the domain of acos
+        let cos = self.dot(other) / (self.length() * other.length());
+        acos(cos.partial_max(-one::<S>()).partial_min(one()))
     }
 }
 
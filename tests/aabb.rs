@@ -79,3 +79,89 @@ fn test_aabb_ray_intersect() {
     assert_eq!((ray3, aabb).intersection(), None);
     assert_eq!((ray4, aabb).intersection(), Some(Point2::new(5.0, 9.0)));
 }
+
+#[test]
+fn test_aabb_boundary() {
+    let aabb = Aabb2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 1.0));
+
+    // points on the min edges are contained
+    assert!(aabb.contains(&Point2::new(0.0, 0.0)));
+    assert!(aabb.contains(&Point2::new(0.0, 0.5)));
+    assert!(aabb.contains(&Point2::new(1.0, 0.0)));
+
+    // points on the max edges are not
+    assert!(!aabb.contains(&Point2::new(2.0, 0.5)));
+    assert!(!aabb.contains(&Point2::new(1.0, 1.0)));
+    assert!(!aabb.contains(&Point2::new(2.0, 1.0)));
+}
+
+#[test]
+fn test_aabb_grow_around_points() {
+    let points = [Point3::new(1.0f64, -2.0, 0.5),
+                  Point3::new(-3.0f64, 4.0, 2.0),
+                  Point3::new(0.0f64, 0.0, -7.0),
+                  Point3::new(2.0f64, 1.0, 1.0)];
+
+    // start with an empty box at the first point and grow it around the rest
+    let mut aabb = Aabb3::new(points[0], points[0]);
+    assert_eq!(aabb.volume(), 0.0);
+    for p in points.iter() {
+        aabb.grow_self(p);
+    }
+
+    assert_eq!(aabb, Aabb3::new(Point3::new(-3.0, -2.0, -7.0), Point3::new(2.0, 4.0, 2.0)));
+    assert_eq!(aabb.dim(), Vector3::new(5.0, 6.0, 9.0));
+    assert_eq!(aabb.center(), Point3::new(-0.5, 1.0, -2.5));
+}
+
+#[test]
+fn test_aabb_intersects() {
+    let a = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+
+    // overlapping
+    assert!(a.intersects(&Aabb3::new(Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 3.0, 3.0))));
+    assert!(a.intersects(&Aabb3::new(Point3::new(0.5, 0.5, 0.5), Point3::new(1.0, 1.0, 1.0))));
+    assert!(a.intersects(&a));
+
+    // touching
+    assert!(a.intersects(&Aabb3::new(Point3::new(2.0, 0.0, 0.0), Point3::new(3.0, 2.0, 2.0))));
+    assert!(a.intersects(&Aabb3::new(Point3::new(2.0, 2.0, 2.0), Point3::new(3.0, 3.0, 3.0))));
+
+    // disjoint along a single axis
+    assert!(!a.intersects(&Aabb3::new(Point3::new(0.0, 0.0, 2.5), Point3::new(2.0, 2.0, 3.0))));
+    assert!(!a.intersects(&Aabb3::new(Point3::new(-3.0, 0.0, 0.0), Point3::new(-1.0, 2.0, 2.0))));
+
+    let b = Aabb2::new(Point2::new(0i, 0i), Point2::new(2i, 2i));
+    assert!(b.intersects(&Aabb2::new(Point2::new(1i, 1i), Point2::new(3i, 3i))));
+    assert!(b.intersects(&Aabb2::new(Point2::new(2i, 0i), Point2::new(3i, 1i))));
+    assert!(!b.intersects(&Aabb2::new(Point2::new(3i, 0i), Point2::new(4i, 1i))));
+}
+
+#[test]
+fn test_aabb_intersect_ray() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+
+    // entering and leaving along an axis
+    let r = Ray::new(Point3::new(-5.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(aabb.intersect_ray(&r), Some((4.0, 6.0)));
+
+    // diagonal ray through opposite corners
+    let r = Ray::new(Point3::new(-2.0f64, -2.0, -2.0), Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(aabb.intersect_ray(&r), Some((1.0, 3.0)));
+
+    // starting inside the box
+    let r = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(aabb.intersect_ray(&r), Some((-1.0, 1.0)));
+
+    // parallel to a slab and outside of it
+    let r = Ray::new(Point3::new(-5.0f64, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(aabb.intersect_ray(&r), None);
+
+    // missing the box entirely
+    let r = Ray::new(Point3::new(-5.0f64, 0.0, 0.0), Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(aabb.intersect_ray(&r), None);
+
+    // the box is behind the ray
+    let r = Ray::new(Point3::new(5.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(aabb.intersect_ray(&r), None);
+}
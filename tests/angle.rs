@@ -20,6 +20,9 @@ extern crate cgmath;
 use cgmath::{Angle, Rad, Deg, rad, deg};
 use cgmath::{ToRad, ToDeg};
 use cgmath::ApproxEq;
+use cgmath::{Matrix, Matrix2, Vector2};
+
+use std::f64;
 
 #[test]
 fn conv() {
@@ -40,3 +43,16 @@ fn equiv() {
     assert!(Rad::<f32>::turn_div_2().equiv(&-Rad::<f32>::turn_div_2()));
     assert!(Rad::<f32>::turn_div_3().sub_a(Rad::<f32>::full_turn()).equiv(&Rad::<f32>::turn_div_3()));
 }
+
+#[test]
+fn half_turn() {
+    assert!(deg(180.0f64).to_rad().approx_eq(&rad(f64::consts::PI)));
+    assert!(rad(f64::consts::PI).to_deg().approx_eq(&deg(180.0f64)));
+    assert!(deg(90.0f64).to_rad().approx_eq(&Rad::turn_div_4()));
+}
+
+#[test]
+fn rotation_from_degrees() {
+    let rot = Matrix2::from_angle(deg(90.0f64).to_rad());
+    assert!(rot.mul_v(&Vector2::unit_x()).approx_eq(&Vector2::unit_y()));
+}
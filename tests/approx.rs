@@ -20,6 +20,8 @@
 extern crate cgmath;
 
 use cgmath::*;
+use std::f32;
+use std::u32;
 
 #[test]
 fn macro_assert_approx_eq_eps() {
@@ -42,3 +44,86 @@ fn macro_assert_approx_eq() {
 fn macro_assert_approx_eq_fail() {
     assert_approx_eq!(1.0f64 / 3.0, 0.333);
 }
+
+#[test]
+fn approx_eq_eps_compound() {
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(1.0f64, 2.001, 3.0);
+    assert!(!a.approx_eq(&b));
+    assert!(a.approx_eq_eps(&b, &0.01));
+
+    let a = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(1.0f64, 2.0, 3.0, 4.001);
+    assert!(!a.approx_eq(&b));
+    assert!(a.approx_eq_eps(&b, &0.01));
+
+    let a = Quaternion::new(1.0f64, 0.0, 0.0, 0.0);
+    let b = Quaternion::new(1.001f64, 0.0, 0.0, 0.0);
+    assert!(!a.approx_eq(&b));
+    assert!(a.approx_eq_eps(&b, &0.01));
+}
+
+#[test]
+fn relative_eq_large_values() {
+    // a fixed tolerance is far too strict for large magnitudes
+    let (a, b) = (1.0e10f64, 1.0e10 + 1.0);
+    assert!(!a.approx_eq(&b));
+    assert!(a.relative_eq(&b, &1.0e-8));
+    assert!(!a.relative_eq(&2.0e10, &1.0e-8));
+}
+
+#[test]
+fn relative_eq_near_zero() {
+    // relative comparison is too strict near zero, where the absolute
+    // tolerance of `approx_eq` is appropriate
+    let (a, b) = (1.0e-10f64, 2.0e-10);
+    assert!(a.approx_eq(&b));
+    assert!(!a.relative_eq(&b, &1.0e-8));
+    assert!(0.0f64.relative_eq(&0.0, &1.0e-8));
+}
+
+#[test]
+fn ulps_eq_scalar() {
+    let a = 1.0f32;
+    let b = 1.0f32 + f32::EPSILON;
+    assert!(a.ulps_eq(&b, 1));
+    assert!(!a.ulps_eq(&(b + f32::EPSILON), 1));
+    assert!(0.0f32.ulps_eq(&-0.0, 0));
+    assert!(!1.0f32.ulps_eq(&-1.0, 4));
+    assert!(!f32::NAN.ulps_eq(&f32::NAN, 4));
+
+    // a large `max_ulps` must not wrap around for f32
+    assert!(1.0f32.ulps_eq(&2.0, u32::MAX));
+}
+
+#[test]
+fn relative_and_ulps_eq_default() {
+    // implementors only need `approx_eq_eps`
+    struct Scalar(f64);
+
+    impl ApproxEq<f64> for Scalar {
+        fn approx_eq_eps(&self, other: &Scalar, epsilon: &f64) -> bool {
+            let (&Scalar(a), &Scalar(b)) = (self, other);
+            a.approx_eq_eps(&b, epsilon)
+        }
+    }
+
+    assert!(Scalar(1.0).relative_eq(&Scalar(1.0 + 1.0e-9), &1.0e-8));
+    assert!(!Scalar(1.0).relative_eq(&Scalar(1.1), &1.0e-8));
+    assert!(Scalar(1.0).ulps_eq(&Scalar(1.0 + 1.0e-9), 4));
+}
+
+#[test]
+fn relative_and_ulps_eq_compound() {
+    let a = Vector3::new(1.0e10f64, 2.0e10, 3.0);
+    let b = Vector3::new(1.0e10f64 + 1.0, 2.0e10, 3.0);
+    assert!(!a.approx_eq(&b));
+    assert!(a.relative_eq(&b, &1.0e-8));
+    assert!(a.ulps_eq(&a, 0));
+
+    let a = Matrix2::new(1.0e10f64, 0.0, 0.0, 1.0e10);
+    let b = Matrix2::new(1.0e10f64 + 1.0, 0.0, 0.0, 1.0e10);
+    assert!(!a.approx_eq(&b));
+    assert!(a.relative_eq(&b, &1.0e-8));
+    assert!(!a.ulps_eq(&b, 4));
+}
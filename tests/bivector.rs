@@ -0,0 +1,57 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+#[test]
+fn test_wedge() {
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(-2.0f64, 0.5, 4.0);
+    assert_eq!(a.wedge(&b).to_vector(), a.cross(&b));
+    assert_eq!(a.wedge(&b), Bivector3::from_wedge(&a, &b));
+    assert_eq!(b.wedge(&a), a.wedge(&b).mul_s(-1.0));
+    assert_eq!(a.wedge(&a), Bivector3::new(0.0, 0.0, 0.0));
+
+    let xy = Vector3::unit_x().wedge(&Vector3::unit_y());
+    assert_eq!(xy, Bivector3::new(0.0f64, 0.0, 1.0));
+    assert_eq!(xy.magnitude(), 1.0);
+}
+
+#[test]
+fn test_exp() {
+    let axes = [
+        Vector3::new(1.0f64, 0.0, 0.0),
+        Vector3::new(0.0f64, 1.0, 0.0),
+        Vector3::new(1.0f64, 2.0, -3.0).normalize(),
+    ];
+    for axis in axes.iter() {
+        for &angle in [0.3f64, 1.0, 2.5].iter() {
+            let b = Bivector3::new(axis.x, axis.y, axis.z).mul_s(angle);
+            let q: Quaternion<f64> = Rotation3::from_axis_angle(axis, rad(angle));
+            assert!(b.exp().approx_eq(&q));
+        }
+    }
+
+    // rotating in the plane of x ∧ y takes x towards y
+    let b = Vector3::unit_x().wedge(&Vector3::unit_y()).mul_s(f64::consts::FRAC_PI_2);
+    assert!(b.exp().mul_v(&Vector3::unit_x()).approx_eq(&Vector3::unit_y()));
+
+    assert_eq!(Bivector3::new(0.0f64, 0.0, 0.0).exp(), Quaternion::identity());
+}
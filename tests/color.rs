@@ -0,0 +1,97 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_channels() {
+    let c = Rgb::new(0.25f64, 0.5, 0.75);
+    assert_eq!((c.r, c.g, c.b), (0.25, 0.5, 0.75));
+
+    let c = Rgba::new(0.25f64, 0.5, 0.75, 1.0);
+    assert_eq!((c.r, c.g, c.b, c.a), (0.25, 0.5, 0.75, 1.0));
+    assert_eq!(c.truncate(), Rgb::new(0.25, 0.5, 0.75));
+    assert_eq!(c.truncate().extend(1.0), c);
+}
+
+#[test]
+fn test_vector_conversion() {
+    let v = Vector3::new(0.25f64, 0.5, 0.75);
+    assert_eq!(Rgb::from_vec3(&v).to_vec3(), v);
+    assert_eq!(Rgb::from_vec3(&v), Rgb::new(0.25, 0.5, 0.75));
+
+    let v = Vector4::new(0.25f64, 0.5, 0.75, 1.0);
+    assert_eq!(Rgba::from_vec4(&v).to_vec4(), v);
+    assert_eq!(Rgba::from_vec4(&v), Rgba::new(0.25, 0.5, 0.75, 1.0));
+}
+
+#[test]
+fn test_lerp() {
+    let black = Rgb::new(0.0f64, 0.0, 0.0);
+    let white = Rgb::new(1.0f64, 1.0, 1.0);
+    assert_eq!(black.lerp(&white, 0.0), black);
+    assert_eq!(black.lerp(&white, 1.0), white);
+    assert_eq!(black.lerp(&white, 0.5), Rgb::new(0.5, 0.5, 0.5));
+
+    let clear = Rgba::new(0.0f64, 0.0, 0.0, 0.0);
+    let white = Rgba::new(1.0f64, 1.0, 1.0, 1.0);
+    assert_eq!(clear.lerp(&white, 0.5), Rgba::new(0.5, 0.5, 0.5, 0.5));
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(Rgb::new(-0.5f64, 0.5, 1.5).clamp(), Rgb::new(0.0, 0.5, 1.0));
+    assert_eq!(Rgba::new(-0.5f64, 0.5, 1.5, 2.0).clamp(), Rgba::new(0.0, 0.5, 1.0, 1.0));
+}
+
+#[test]
+fn test_from_hsv() {
+    assert!(Rgb::from_hsv(deg(0.0f64), 1.0, 1.0).approx_eq(&Rgb::new(1.0, 0.0, 0.0)));
+    assert!(Rgb::from_hsv(deg(120.0f64), 1.0, 1.0).approx_eq(&Rgb::new(0.0, 1.0, 0.0)));
+    assert!(Rgb::from_hsv(deg(240.0f64), 1.0, 1.0).approx_eq(&Rgb::new(0.0, 0.0, 1.0)));
+    assert!(Rgb::from_hsv(deg(360.0f64), 1.0, 1.0).approx_eq(&Rgb::new(1.0, 0.0, 0.0)));
+    assert!(Rgb::from_hsv(deg(-120.0f64), 1.0, 1.0).approx_eq(&Rgb::new(0.0, 0.0, 1.0)));
+    assert!(Rgb::from_hsv(deg(200.0f64), 0.0, 0.5).approx_eq(&Rgb::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn test_to_hsv() {
+    let (h, s, v) = Rgb::new(1.0f64, 0.0, 0.0).to_hsv();
+    assert_eq!((h.s, s, v), (0.0, 1.0, 1.0));
+    let (h, s, v) = Rgb::new(0.0f64, 1.0, 0.0).to_hsv();
+    assert_eq!((h.s, s, v), (120.0, 1.0, 1.0));
+    let (h, s, v) = Rgb::new(0.0f64, 0.0, 1.0).to_hsv();
+    assert_eq!((h.s, s, v), (240.0, 1.0, 1.0));
+
+    // achromatic colors have no defined hue
+    let (h, s, v) = Rgb::new(0.5f64, 0.5, 0.5).to_hsv();
+    assert_eq!((h.s, s, v), (0.0, 0.0, 0.5));
+    let (h, s, v) = Rgb::new(0.0f64, 0.0, 0.0).to_hsv();
+    assert_eq!((h.s, s, v), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_hsv_round_trip() {
+    let c = Rgb::new(0.8f64, 0.5, 0.35);
+    let (h, s, v) = c.to_hsv();
+    assert!(h.approx_eq(&deg(20.0)));
+    assert!(s.approx_eq(&0.5625));
+    assert!(v.approx_eq(&0.8));
+    assert!(Rgb::from_hsv(h, s, v).approx_eq(&c));
+}
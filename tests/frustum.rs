@@ -0,0 +1,80 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_contains() {
+    let f = Frustum::from_matrix4(perspective(deg(90.0f64), 1.0, 1.0, 100.0));
+
+    assert!(f.contains(&Point3::new(0.0, 0.0, -10.0)));
+    assert!(f.contains(&Point3::new(4.0, -4.0, -5.0)));
+
+    // behind the near plane, or behind the eye entirely
+    assert!(!f.contains(&Point3::new(0.0, 0.0, -0.5)));
+    assert!(!f.contains(&Point3::new(0.0, 0.0, 10.0)));
+
+    // beyond the far plane and outside the sides
+    assert!(!f.contains(&Point3::new(0.0, 0.0, -101.0)));
+    assert!(!f.contains(&Point3::new(6.0, 0.0, -5.0)));
+    assert!(!f.contains(&Point3::new(0.0, -6.0, -5.0)));
+}
+
+#[test]
+fn test_view_projection() {
+    // planes extracted from a view-projection matrix are in world space
+    let view = Matrix4::look_at(&Point3::new(10.0f64, 0.0, 0.0),
+                                &Point3::new(0.0, 0.0, 0.0),
+                                &Vector3::unit_y());
+    let proj = perspective(deg(60.0f64), 1.0, 1.0, 50.0);
+    let f = Frustum::from_matrix4(proj.mul_m(&view));
+
+    assert!(f.contains(&Point3::new(0.0, 0.0, 0.0)));
+    assert!(!f.contains(&Point3::new(20.0, 0.0, 0.0)));
+}
+
+#[test]
+fn test_intersects_sphere() {
+    let f = Frustum::from_matrix4(perspective(deg(90.0f64), 1.0, 1.0, 100.0));
+
+    assert!(f.intersects_sphere(&Sphere { center: Point3::new(0.0, 0.0, -10.0), radius: 1.0 }));
+
+    // the center is outside but the sphere crosses the near plane
+    assert!(f.intersects_sphere(&Sphere { center: Point3::new(0.0, 0.0, 0.0), radius: 2.0 }));
+
+    assert!(!f.intersects_sphere(&Sphere { center: Point3::new(0.0, 0.0, 5.0), radius: 2.0 }));
+    assert!(!f.intersects_sphere(&Sphere { center: Point3::new(20.0, 0.0, -5.0), radius: 2.0 }));
+}
+
+#[test]
+fn test_ortho_to_frustum() {
+    let o = Ortho { left: -1.0f64, right: 2.0, bottom: -3.0, top: 4.0, near: 1.0, far: 10.0 };
+    let a = o.to_frustum();
+    let b = Frustum::from_matrix4(o.to_matrix4());
+
+    assert!(a.left.approx_eq(&b.left));
+    assert!(a.right.approx_eq(&b.right));
+    assert!(a.bottom.approx_eq(&b.bottom));
+    assert!(a.top.approx_eq(&b.top));
+    assert!(a.near.approx_eq(&b.near));
+    assert!(a.far.approx_eq(&b.far));
+
+    assert!(a.contains(&Point3::new(0.0, 0.0, -5.0)));
+    assert!(!a.contains(&Point3::new(3.0, 0.0, -5.0)));
+}
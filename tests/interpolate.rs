@@ -0,0 +1,131 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn midpoint<S: BaseFloat, T: Lerp<S>>(a: &T, b: &T) -> T {
+    a.lerp(b, one::<S>() / (one::<S>() + one::<S>()))
+}
+
+#[test]
+fn test_lerp_scalar() {
+    assert_eq!(2.0f64.lerp(&6.0, 0.0), 2.0);
+    assert_eq!(2.0f64.lerp(&6.0, 1.0), 6.0);
+    assert_eq!(2.0f64.lerp(&6.0, 0.25), 3.0);
+    assert_eq!(midpoint(&-1.0f32, &3.0), 1.0);
+}
+
+#[test]
+fn test_lerp_vector() {
+    assert_eq!(midpoint(&Vector2::new(0.0f64, 2.0), &Vector2::new(4.0, -2.0)),
+               Vector2::new(2.0, 0.0));
+    assert_eq!(midpoint(&Vector3::new(0.0f64, 2.0, 4.0), &Vector3::new(4.0, -2.0, 0.0)),
+               Vector3::new(2.0, 0.0, 2.0));
+    assert_eq!(midpoint(&Vector4::new(0.0f64, 2.0, 4.0, 6.0), &Vector4::new(4.0, -2.0, 0.0, 6.0)),
+               Vector4::new(2.0, 0.0, 2.0, 6.0));
+}
+
+#[test]
+fn test_lerp_matrix() {
+    let a = Matrix2::<f64>::identity();
+    let b = Matrix2::new(3.0f64, 2.0, -2.0, 3.0);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(midpoint(&a, &b), Matrix2::new(2.0, 1.0, -1.0, 2.0));
+
+    let a = Matrix3::<f64>::zero();
+    let b = Matrix3::from_value(4.0f64);
+    assert_eq!(midpoint(&a, &b), Matrix3::from_value(2.0));
+
+    let a = Matrix4::<f64>::identity();
+    let b = Matrix4::from_value(3.0f64);
+    assert_eq!(a.lerp(&b, 0.5), Matrix4::from_value(2.0));
+}
+
+#[test]
+fn test_catmull_rom_endpoints() {
+    let p0 = Vector3::new(0.0f64, 0.0, 0.0);
+    let p1 = Vector3::new(1.0f64, 2.0, 0.0);
+    let p2 = Vector3::new(3.0f64, 2.0, 1.0);
+    let p3 = Vector3::new(4.0f64, 0.0, 1.0);
+    assert_eq!(catmull_rom(&p0, &p1, &p2, &p3, 0.0), p1);
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 1.0).approx_eq(&p2));
+
+    // evenly spaced collinear points are interpolated linearly
+    let p0 = Vector3::new(0.0f64, 0.0, 0.0);
+    let p1 = Vector3::new(1.0f64, 1.0, 1.0);
+    let p2 = Vector3::new(2.0f64, 2.0, 2.0);
+    let p3 = Vector3::new(3.0f64, 3.0, 3.0);
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 0.25).approx_eq(&Vector3::new(1.25, 1.25, 1.25)));
+}
+
+#[test]
+fn test_catmull_rom_bounded() {
+    // control points evenly spaced around the unit circle
+    let p0 = Vector2::new(1.0f64, 0.0);
+    let p1 = Vector2::new(0.0f64, 1.0);
+    let p2 = Vector2::new(-1.0f64, 0.0);
+    let p3 = Vector2::new(0.0f64, -1.0);
+    assert_eq!(catmull_rom(&p0, &p1, &p2, &p3, 0.0), p1);
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 1.0).approx_eq(&p2));
+
+    for i in range(0u, 11) {
+        let t = i as f64 / 10.0;
+        let p = catmull_rom(&p0, &p1, &p2, &p3, t);
+        assert!(p.x <= 0.0 && p.x >= -1.0);
+        assert!(p.y >= 0.0 && p.y <= 1.0);
+        assert!(p.length() <= 1.0);
+    }
+}
+
+#[test]
+fn test_bezier2() {
+    let p0 = Vector2::new(0.0f64, 0.0);
+    let p1 = Vector2::new(1.0f64, 2.0);
+    let p2 = Vector2::new(2.0f64, 0.0);
+    assert_eq!(bezier2(&p0, &p1, &p2, 0.0), p0);
+    assert_eq!(bezier2(&p0, &p1, &p2, 1.0), p2);
+    assert_eq!(bezier2(&p0, &p1, &p2, 0.5), Vector2::new(1.0, 1.0));
+
+    let p0 = Vector3::new(0.0f64, 0.0, 1.0);
+    let p1 = Vector3::new(1.0f64, 2.0, 1.0);
+    let p2 = Vector3::new(2.0f64, 0.0, 1.0);
+    assert_eq!(bezier2(&p0, &p1, &p2, 0.0), p0);
+    assert_eq!(bezier2(&p0, &p1, &p2, 1.0), p2);
+    assert_eq!(bezier2(&p0, &p1, &p2, 0.5), Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_bezier3() {
+    let p0 = Vector2::new(0.0f64, 0.0);
+    let p1 = Vector2::new(0.0f64, 4.0);
+    let p2 = Vector2::new(4.0f64, 4.0);
+    let p3 = Vector2::new(4.0f64, 0.0);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 0.0), p0);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 1.0), p3);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 0.5), Vector2::new(2.0, 3.0));
+
+    let p0 = Vector3::new(0.0f64, 0.0, -2.0);
+    let p1 = Vector3::new(0.0f64, 4.0, -2.0);
+    let p2 = Vector3::new(4.0f64, 4.0, 2.0);
+    let p3 = Vector3::new(4.0f64, 0.0, 2.0);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 0.0), p0);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 1.0), p3);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 0.5), Vector3::new(2.0, 3.0, 0.0));
+}
@@ -19,6 +19,7 @@ extern crate cgmath;
 
 use cgmath::*;
 use std::f64;
+use std::num::Float;
 
 pub mod matrix2 {
     use cgmath::*;
@@ -200,6 +201,15 @@ fn test_mul_v() {
     assert_eq!(matrix4::A.mul_v(&matrix4::V), Vector4::new(30.0f64, 70.0f64, 110.0f64, 150.0f64));
 }
 
+#[test]
+fn test_mul_v_operator() {
+    assert_eq!(matrix2::A * matrix2::V, matrix2::A.mul_v(&matrix2::V));
+    assert_eq!(matrix3::A * matrix3::V, matrix3::A.mul_v(&matrix3::V));
+    assert_eq!(matrix4::A * matrix4::V, matrix4::A.mul_v(&matrix4::V));
+    assert_eq!(matrix4::A + matrix4::B, matrix4::A.add_m(&matrix4::B));
+    assert_eq!(matrix4::A - matrix4::B, matrix4::A.sub_m(&matrix4::B));
+}
+
 #[test]
 fn test_mul_m() {
     assert_eq!(matrix2::A.mul_m(&matrix2::B),
@@ -220,6 +230,16 @@ fn test_mul_m() {
     assert_eq!(matrix4::A.mul_m(&matrix4::B), matrix4::A * matrix4::B);
 }
 
+#[test]
+fn test_transpose_mul() {
+    assert_eq!(matrix2::A.transpose_mul(&matrix2::B), matrix2::A.transpose().mul_m(&matrix2::B));
+    assert_eq!(matrix3::A.transpose_mul(&matrix3::B), matrix3::A.transpose().mul_m(&matrix3::B));
+    assert_eq!(matrix4::A.transpose_mul(&matrix4::B), matrix4::A.transpose().mul_m(&matrix4::B));
+
+    assert_eq!(matrix3::C.transpose_mul(&matrix3::D), matrix3::C.transpose().mul_m(&matrix3::D));
+    assert_eq!(matrix4::C.transpose_mul(&matrix4::D), matrix4::C.transpose().mul_m(&matrix4::D));
+}
+
 #[test]
 fn test_determinant() {
     assert_eq!(matrix2::A.determinant(), -2.0f64);
@@ -227,6 +247,27 @@ fn test_determinant() {
     assert_eq!(matrix4::A.determinant(), 0.0f64);
 }
 
+#[test]
+fn test_determinant_lu() {
+    assert!(matrix4::A.determinant_lu().approx_eq(&0.0));
+    assert!(matrix4::C.determinant_lu().approx_eq(&-8.0));
+    assert!(matrix4::D.determinant_lu().approx_eq(&20.0));
+    assert!(matrix4::C.determinant_lu().approx_eq(&matrix4::C.determinant()));
+    assert!(matrix4::D.determinant_lu().approx_eq(&matrix4::D.determinant()));
+
+    // a zero in the top left corner needs a row swap, which flips the sign
+    let swap = Matrix4::new(0.0f64, 1.0, 0.0, 0.0,
+                            1.0, 0.0, 0.0, 0.0,
+                            0.0, 0.0, 1.0, 0.0,
+                            0.0, 0.0, 0.0, 1.0);
+    assert_eq!(swap.determinant_lu(), -1.0);
+    assert_eq!(Matrix4::<f64>::identity().determinant_lu(), 1.0);
+
+    let m = Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0))
+        .mul_m(&Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0));
+    assert!(m.determinant_lu().approx_eq(&24.0));
+}
+
 #[test]
 fn test_trace() {
     assert_eq!(matrix2::A.trace(), 5.0f64);
@@ -334,6 +375,28 @@ fn test_from_translation() {
     let vertex = Vector4::new(0.0f64, 0.0f64, 0.0f64, 1.0f64);
     let res = mat.mul_v(&vertex);
     assert_eq!(res, Vector4::new(1., 2., 3., 1.));
+
+    // points are shifted
+    let point = Vector4::new(-4.0f64, 0.5f64, 10.0f64, 1.0f64);
+    assert_eq!(mat.mul_v(&point), Vector4::new(-3.0, 2.5, 13.0, 1.0));
+
+    // directions are left alone
+    let dir = Vector4::new(-4.0f64, 0.5f64, 10.0f64, 0.0f64);
+    assert_eq!(mat.mul_v(&dir), dir);
+}
+
+#[test]
+fn test_from_scale() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    assert_eq!(Matrix3::from_scale(2.0f64).mul_v(&v), Vector3::new(2.0, -4.0, 6.0));
+    assert_eq!(Matrix3::from_nonuniform_scale(2.0f64, 3.0, -1.0).mul_v(&v),
+               Vector3::new(2.0, -6.0, -3.0));
+
+    // the homogeneous coordinate is not scaled
+    let v = Vector4::new(1.0f64, -2.0, 3.0, 1.0);
+    assert_eq!(Matrix4::from_scale(2.0f64).mul_v(&v), Vector4::new(2.0, -4.0, 6.0, 1.0));
+    assert_eq!(Matrix4::from_nonuniform_scale(2.0f64, 3.0, -1.0).mul_v(&v),
+               Vector4::new(2.0, -6.0, -3.0, 1.0));
 }
 
 #[test]
@@ -410,3 +473,772 @@ fn test_from_angle() {
     let rot3: Matrix2<f64> = Matrix2::from_angle(rad(f64::consts::PI));
     assert!(rot3.mul_v(&Vector2::new(1.0, 1.0)).approx_eq(&Vector2::new(-1.0, -1.0)));
 }
+
+#[test]
+fn test_look_at() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(4.0f64, -1.0, 0.5);
+    let view = Matrix4::look_at(&eye, &center, &Vector3::unit_y());
+
+    // the eye is moved to the origin
+    assert!(view.mul_v(&eye.to_homogeneous()).approx_eq(&Vector4::new(0.0, 0.0, 0.0, 1.0)));
+
+    // the target ends up straight ahead, down the negative z axis
+    let target = view.mul_v(&center.to_homogeneous());
+    assert!(target.x.approx_eq(&0.0));
+    assert!(target.y.approx_eq(&0.0));
+    assert!(target.z < 0.0);
+
+    // looking down -z with +y up leaves the basis unchanged
+    let eye = Point3::new(0.0f64, 0.0, 5.0);
+    let view = Matrix4::look_at(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    assert!(view.approx_eq(&Matrix4::from_translation(&Vector3::new(0.0, 0.0, -5.0))));
+}
+
+#[test]
+fn test_row() {
+    // each row must read one element from every column, including the last
+    let m = Matrix4::new( 1.0f64,  2.0,  3.0,  4.0,
+                          5.0,  6.0,  7.0,  8.0,
+                          9.0, 10.0, 11.0, 12.0,
+                         13.0, 14.0, 15.0, 16.0);
+    assert_eq!(m.row(0), Vector4::new(1.0, 5.0,  9.0, 13.0));
+    assert_eq!(m.row(3), Vector4::new(4.0, 8.0, 12.0, 16.0));
+
+    // check the w component of a non-symmetric product on its own
+    let v = Vector4::new(1.0f64, -1.0, 2.0, 0.5);
+    let res = m.mul_v(&v);
+    assert_eq!(res.w, 4.0 * 1.0 + 8.0 * -1.0 + 12.0 * 2.0 + 16.0 * 0.5);
+    assert_eq!(res.w, m.row(3).dot(&v));
+}
+
+#[test]
+fn test_from_angle_xyz() {
+    let quarter = rad(0.5f64 * f64::consts::PI);
+
+    // a quarter turn about each axis maps the next axis onto the one after
+    let rot_x: Matrix3<f64> = Matrix3::from_angle_x(quarter);
+    assert!(rot_x.mul_v(&Vector3::unit_y()).approx_eq(&Vector3::unit_z()));
+    assert!(rot_x.mul_v(&Vector3::unit_z()).approx_eq(&-Vector3::unit_y()));
+    assert!(rot_x.mul_v(&Vector3::unit_x()).approx_eq(&Vector3::unit_x()));
+
+    let rot_y: Matrix3<f64> = Matrix3::from_angle_y(quarter);
+    assert!(rot_y.mul_v(&Vector3::unit_z()).approx_eq(&Vector3::unit_x()));
+    assert!(rot_y.mul_v(&Vector3::unit_x()).approx_eq(&-Vector3::unit_z()));
+    assert!(rot_y.mul_v(&Vector3::unit_y()).approx_eq(&Vector3::unit_y()));
+
+    let rot_z: Matrix3<f64> = Matrix3::from_angle_z(quarter);
+    assert!(rot_z.mul_v(&Vector3::unit_x()).approx_eq(&Vector3::unit_y()));
+    assert!(rot_z.mul_v(&Vector3::unit_y()).approx_eq(&-Vector3::unit_x()));
+    assert!(rot_z.mul_v(&Vector3::unit_z()).approx_eq(&Vector3::unit_z()));
+}
+
+#[test]
+fn test_from_axis_angle() {
+    let angle = rad(0.3f64 * f64::consts::PI);
+
+    // rotations about the principal axes match the specialised constructors
+    assert!(Matrix3::from_axis_angle(&Vector3::unit_x(), angle).approx_eq(&Matrix3::from_angle_x(angle)));
+    assert!(Matrix3::from_axis_angle(&Vector3::unit_y(), angle).approx_eq(&Matrix3::from_angle_y(angle)));
+    assert!(Matrix3::from_axis_angle(&Vector3::unit_z(), angle).approx_eq(&Matrix3::from_angle_z(angle)));
+
+    // the axis is normalized first
+    assert!(Matrix3::from_axis_angle(&Vector3::new(0.0f64, 0.0, 4.0), angle).approx_eq(&Matrix3::from_angle_z(angle)));
+
+    // the result is orthonormal
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, -3.0), angle);
+    assert!(rot.transpose().approx_eq(&rot.invert().unwrap()));
+    assert!(rot.determinant().approx_eq(&1.0));
+}
+
+#[test]
+fn test_from_euler() {
+    let (x, y, z) = (rad(0.1f64), rad(-0.7f64), rad(2.3f64));
+    let expected = Matrix3::from_angle_z(z)
+                   .mul_m(&Matrix3::from_angle_y(y))
+                   .mul_m(&Matrix3::from_angle_x(x));
+    assert!(Matrix3::from_euler(x, y, z).approx_eq(&expected));
+
+    // a single angle is a rotation about that axis
+    assert!(Matrix3::from_euler(x, rad(0.0), rad(0.0)).approx_eq(&Matrix3::from_angle_x(x)));
+    assert!(Matrix3::from_euler(rad(0.0), y, rad(0.0)).approx_eq(&Matrix3::from_angle_y(y)));
+    assert!(Matrix3::from_euler(rad(0.0), rad(0.0), z).approx_eq(&Matrix3::from_angle_z(z)));
+}
+
+#[test]
+fn test_index() {
+    let mut m = matrix3::C;
+    assert_eq!(m[0], m.x);
+    assert_eq!(m[2], m.z);
+    assert_eq!(m[1][2], m.y.z);
+
+    // writing through an index updates the column
+    m[1][0] = 42.0;
+    assert_eq!(m.y.x, 42.0);
+    m[2] = Vector3::new(7.0, 8.0, 9.0);
+    assert_eq!(m.z, Vector3::new(7.0, 8.0, 9.0));
+}
+
+#[test]
+#[should_fail]
+fn test_index_out_of_bounds() {
+    let m = matrix2::A;
+    m[2];
+}
+
+#[test]
+fn test_orthonormalize() {
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, -3.0), rad(1.2f64));
+    let mut drifted = rot;
+    drifted[0][1] += 0.01;
+    drifted[1][2] -= 0.02;
+    drifted[2][0] += 0.015;
+    assert!(!drifted.transpose().approx_eq(&drifted.invert().unwrap()));
+
+    let fixed = drifted.orthonormalize();
+    assert!(fixed.transpose().approx_eq(&fixed.invert().unwrap()));
+    assert!(fixed.determinant().approx_eq(&1.0));
+
+    // an orthonormal matrix is left unchanged
+    assert!(rot.orthonormalize().approx_eq(&rot));
+
+    let mut m = drifted;
+    m.orthonormalize_self();
+    assert_eq!(m, fixed);
+}
+
+#[test]
+fn test_is_orthogonal() {
+    let angle = rad(0.7f64);
+    assert!(Matrix2::from_angle(angle).is_orthogonal());
+    assert!(Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, -3.0), angle).is_orthogonal());
+    assert!(Matrix3::from_axis_angle(&Vector3::unit_z(), angle).to_matrix4().is_orthogonal());
+    assert!(Matrix4::<f64>::identity().is_orthogonal());
+
+    assert!(!Matrix3::from_scale(2.0f64).is_orthogonal());
+    assert!(!Matrix4::from_nonuniform_scale(1.0f64, 1.0, 0.5).is_orthogonal());
+}
+
+#[test]
+fn test_adjugate() {
+    // Matrix2
+    assert_eq!(matrix2::A.adjugate(), Matrix2::new(4.0f64, -3.0, -2.0, 1.0));
+    for m in [matrix2::A, matrix2::B, matrix2::C].iter() {
+        let expected = Matrix2::identity().mul_s(m.determinant());
+        assert!(m.mul_m(&m.adjugate()).approx_eq(&expected));
+    }
+
+    // Matrix3
+    for m in [matrix3::A, matrix3::B, matrix3::C, matrix3::D].iter() {
+        let expected = Matrix3::identity().mul_s(m.determinant());
+        assert!(m.mul_m(&m.adjugate()).approx_eq(&expected));
+    }
+
+    // Matrix4
+    for m in [matrix4::A, matrix4::B, matrix4::C, matrix4::D].iter() {
+        let expected = Matrix4::identity().mul_s(m.determinant());
+        assert!(m.mul_m(&m.adjugate()).approx_eq(&expected));
+    }
+}
+
+#[test]
+fn test_from_outer() {
+    let a = Vector2::new(1.0f64, 2.0);
+    let b = Vector2::new(3.0f64, -4.0);
+    let m = Matrix2::from_outer(&a, &b);
+    for c in range(0u, 2) { assert_eq!(m[c], a.mul_s(b[c])); }
+    assert!(m.determinant().approx_eq(&0.0));
+
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(-4.0f64, 5.0, 0.5);
+    let m = Matrix3::from_outer(&a, &b);
+    for c in range(0u, 3) { assert_eq!(m[c], a.mul_s(b[c])); }
+    assert_eq!(m[1][2], a.z * b.y);
+    assert!(m.determinant().approx_eq(&0.0));
+
+    let a = Vector4::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = Vector4::new(-4.0f64, 5.0, 0.5, 2.0);
+    let m = Matrix4::from_outer(&a, &b);
+    for c in range(0u, 4) { assert_eq!(m[c], a.mul_s(b[c])); }
+    assert!(m.determinant().approx_eq(&0.0));
+}
+
+#[test]
+fn test_resize() {
+    // embedding into a larger identity matrix and truncating round-trips
+    assert_eq!(matrix2::A.to_matrix3().truncate(), matrix2::A);
+    assert_eq!(matrix3::A.to_matrix4().truncate(), matrix3::A);
+    assert_eq!(matrix2::A.to_matrix4().truncate().truncate(), matrix2::A);
+
+    let m = matrix3::D.to_matrix4();
+    assert_eq!(m.w, Vector4::unit_w());
+    assert_eq!(m.row(3), Vector4::unit_w());
+
+    assert_eq!(matrix4::A.truncate(), Matrix3::new(1.0f64, 5.0, 9.0,
+                                                   2.0, 6.0, 10.0,
+                                                   3.0, 7.0, 11.0));
+}
+
+#[test]
+fn test_show() {
+    assert_eq!(format!("{}", Matrix2::<int>::identity()),
+               "[[1, 0],\n [0, 1]]".to_string());
+
+    // rows are printed left to right, even though storage is column-major,
+    // and each column is padded to the width of its widest element
+    assert_eq!(format!("{}", Matrix3::new(1i, 20, 3,
+                                          -4, 5, 600,
+                                          7, 8, 9)),
+               "[[ 1,  -4, 7],\n [20,   5, 8],\n [ 3, 600, 9]]".to_string());
+}
+
+#[test]
+fn test_flat_access() {
+    use std::mem;
+
+    // the columns are packed without padding
+    assert_eq!(mem::size_of::<Matrix2<f32>>(), 4 * mem::size_of::<f32>());
+    assert_eq!(mem::size_of::<Matrix3<f32>>(), 9 * mem::size_of::<f32>());
+    assert_eq!(mem::size_of::<Matrix4<f64>>(), 16 * mem::size_of::<f64>());
+
+    let id = Matrix4::<f32>::identity();
+    assert_eq!(id.as_slice(), [1.0f32, 0.0, 0.0, 0.0,
+                               0.0, 1.0, 0.0, 0.0,
+                               0.0, 0.0, 1.0, 0.0,
+                               0.0, 0.0, 0.0, 1.0].as_slice());
+    assert_eq!(matrix3::A.as_slice(), [1.0f64, 4.0, 7.0,
+                                       2.0, 5.0, 8.0,
+                                       3.0, 6.0, 9.0].as_slice());
+    assert_eq!(unsafe { *matrix2::A.as_ptr().offset(2) }, matrix2::A.y.x);
+
+    let mut m = matrix2::A;
+    m.as_mut_slice()[3] = 10.0;
+    assert_eq!(m.y.y, 10.0);
+    unsafe { *m.as_mut_ptr().offset(1) = 20.0; }
+    assert_eq!(m.x.y, 20.0);
+}
+
+#[test]
+fn test_cast() {
+    let m: Matrix4<f32> = matrix4::A.cast();
+    assert_eq!(m.cast::<f64>(), matrix4::A);
+    assert_eq!(matrix3::A.cast::<f32>().z, Vector3::new(3.0f32, 6.0, 9.0));
+    assert_eq!(Matrix2::new(1.5f64, 2.0, -3.0, 4.9).cast::<int>(), Matrix2::new(1i, 2, -3, 4));
+}
+
+#[test]
+fn test_powi() {
+    assert_eq!(matrix2::A.powi(0), Matrix2::identity());
+    assert_eq!(matrix2::A.powi(1), matrix2::A);
+    assert_eq!(matrix2::A.powi(3), matrix2::A.mul_m(&matrix2::A).mul_m(&matrix2::A));
+
+    assert_eq!(matrix3::A.powi(0), Matrix3::identity());
+    assert!(matrix3::D.powi(3).approx_eq(&matrix3::D.mul_m(&matrix3::D).mul_m(&matrix3::D)));
+
+    assert_eq!(matrix4::A.powi(0), Matrix4::identity());
+    assert!(matrix4::C.powi(3).approx_eq(&matrix4::C.mul_m(&matrix4::C).mul_m(&matrix4::C)));
+
+    // a quarter turn applied four times is a full turn
+    let rot = Matrix2::from_angle(rad(f64::consts::FRAC_PI_2));
+    assert!(rot.powi(4).approx_eq(&Matrix2::identity()));
+    assert!(rot.powi(10).approx_eq(&rot.powi(2)));
+}
+
+#[test]
+fn test_qr() {
+    let m = Matrix3::new(12.0f64, 6.0, -4.0,
+                         -51.0, 167.0, 24.0,
+                         4.0, -68.0, -41.0);
+    let (q, r) = m.qr();
+    assert!(q.is_orthogonal());
+    assert!(r[0][1].approx_eq(&0.0));
+    assert!(r[0][2].approx_eq(&0.0));
+    assert!(r[1][2].approx_eq(&0.0));
+    assert!(q.mul_m(&r).approx_eq(&m));
+
+    let (q, r) = matrix4::D.qr();
+    assert!(q.is_orthogonal());
+    for c in range(0u, 4) {
+        for row in range(c + 1, 4) {
+            assert!(r[c][row].approx_eq(&0.0));
+        }
+    }
+    assert!(q.mul_m(&r).approx_eq(&matrix4::D));
+}
+
+#[test]
+fn test_symmetric_eigen() {
+    // the eigenvalues of a diagonal matrix are its diagonal
+    let (values, vectors) = Matrix3::from_nonuniform_scale(3.0f64, -1.0, 2.0).symmetric_eigen();
+    assert_eq!(values, Vector3::new(3.0, -1.0, 2.0));
+    assert_eq!(vectors, Matrix3::identity());
+
+    // eigenvalues 1, 3 and 3
+    let m = Matrix3::new(2.0f64, 1.0, 0.0,
+                         1.0, 2.0, 0.0,
+                         0.0, 0.0, 3.0);
+    let (values, vectors) = m.symmetric_eigen();
+    assert!(values.approx_eq(&Vector3::new(1.0, 3.0, 3.0)));
+    assert!(vectors.is_orthogonal());
+    for i in range(0u, 3) {
+        assert!(m.mul_v(&vectors[i]).approx_eq(&vectors[i].mul_s(values[i])));
+    }
+
+    let (values, vectors) = matrix3::D.symmetric_eigen();
+    assert!(values.comp_add().approx_eq(&matrix3::D.trace()));
+    assert!(values.comp_mul().approx_eq(&matrix3::D.determinant()));
+    for i in range(0u, 3) {
+        assert!(matrix3::D.mul_v(&vectors[i]).approx_eq(&vectors[i].mul_s(values[i])));
+    }
+
+    // the stopping tolerance is relative, so tiny matrices are still solved
+    let tiny = m.mul_s(1.0e-7);
+    let (values, vectors) = tiny.symmetric_eigen();
+    assert!(values.mul_s(1.0e7).approx_eq(&Vector3::new(1.0, 3.0, 3.0)));
+    assert!(!vectors.is_identity());
+    for i in range(0u, 3) {
+        assert!(m.mul_v(&vectors[i]).approx_eq(&vectors[i].mul_s(values[i] * 1.0e7)));
+    }
+
+    assert_eq!(Matrix3::<f64>::zero().symmetric_eigen(), (Vector3::zero(), Matrix3::identity()));
+}
+
+#[test]
+#[should_fail]
+fn test_symmetric_eigen_non_symmetric() {
+    matrix3::A.symmetric_eigen();
+}
+
+#[test]
+fn test_deviator() {
+    assert!(matrix2::A.deviator().trace().approx_eq(&0.0));
+    assert!(matrix3::A.deviator().trace().approx_eq(&0.0));
+    assert!(matrix3::D.deviator().trace().approx_eq(&0.0));
+    assert!(matrix4::A.deviator().trace().approx_eq(&0.0));
+    assert!(matrix4::C.deviator().trace().approx_eq(&0.0));
+
+    // only the diagonal is affected
+    assert_eq!(matrix2::A.deviator(), Matrix2::new(-1.5f64, 3.0, 2.0, 1.5));
+
+    // a uniform scale is purely isotropic
+    assert!(Matrix3::from_scale(4.0f64).deviator().approx_eq(&Matrix3::zero()));
+}
+
+#[test]
+fn test_from_iter() {
+    let cols = vec![matrix3::A.x, matrix3::A.y, matrix3::A.z];
+    let m: Matrix3<f64> = cols.into_iter().collect();
+    assert_eq!(m, matrix3::A);
+
+    let m: Matrix2<f64> = range(0u, 2).map(|i| matrix2::B[i]).collect();
+    assert_eq!(m, matrix2::B);
+}
+
+#[test]
+#[should_fail]
+fn test_from_iter_too_short() {
+    let _: Matrix4<f64> = vec![Vector4::unit_x(), Vector4::unit_y()].into_iter().collect();
+}
+
+#[test]
+fn test_elements() {
+    let elems: Vec<f64> = Matrix3::identity().elements().map(|&x| x).collect();
+    assert_eq!(elems, vec![1.0, 0.0, 0.0,
+                           0.0, 1.0, 0.0,
+                           0.0, 0.0, 1.0]);
+
+    assert_eq!(matrix4::A.elements().count(), 16);
+    assert_eq!(matrix2::A.elements().fold(0.0, |sum, &x| sum + x), 10.0);
+}
+
+#[test]
+fn test_is_rotated() {
+    assert!(!Matrix3::<f64>::identity().is_rotated());
+    assert!(!Matrix3::from_nonuniform_scale(2.0f64, 3.0, 0.5).is_rotated());
+    assert!(!Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0)).is_rotated());
+    assert!(!Matrix4::from_scale(3.0f64).is_rotated());
+
+    let angle = deg(30.0f64).to_rad();
+    assert!(Matrix2::from_angle(angle).is_rotated());
+    assert!(Matrix3::from_angle_y(angle).is_rotated());
+    assert!(Matrix3::from_angle_y(angle).mul_m(&Matrix3::from_scale(2.0)).is_rotated());
+    assert!(Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0))
+            .mul_m(&Matrix3::from_angle_z(angle).to_matrix4()).is_rotated());
+
+    // mirrors are a negative scale along one axis, not rotations
+    assert!(!Matrix3::from_nonuniform_scale(-1.0f64, 1.0, 1.0).is_rotated());
+    assert!(!Matrix4::from_nonuniform_scale(1.0f64, -2.0, 1.0).is_rotated());
+    assert!(!Matrix2::new(-1.0f64, 0.0, 0.0, 3.0).is_rotated());
+    assert!(Matrix3::from_angle_x(angle).mul_m(&Matrix3::from_nonuniform_scale(-1.0, 1.0, 1.0)).is_rotated());
+
+    // a half turn is a negative scale along two axes, and is rotated
+    let half = deg(180.0f64).to_rad();
+    assert!(Matrix3::from_angle_z(half).is_rotated());
+    assert!(Matrix3::from_nonuniform_scale(-1.0f64, -1.0, 1.0).is_rotated());
+    assert!(Matrix2::from_angle(half).is_rotated());
+    assert!(Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0))
+            .mul_m(&Matrix3::from_angle_y(half).to_matrix4()).is_rotated());
+
+    // singular scales are not rotated
+    assert!(!Matrix3::from_nonuniform_scale(-1.0f64, 0.0, 0.0).is_rotated());
+    assert!(!Matrix3::from_nonuniform_scale(1.0f64, 1.0, 0.0).is_rotated());
+
+    // shears are rotated, whichever way round they are
+    let shear = Matrix3::from_shear(0.5f64, 0.0, 0.0, 0.0, 0.0, 0.0);
+    assert!(shear.is_rotated());
+    assert!(shear.transpose().is_rotated());
+    let shear = Matrix2::new(1.0f64, 0.0, 0.5, 1.0);
+    assert!(shear.is_rotated());
+    assert!(shear.transpose().is_rotated());
+}
+
+#[test]
+fn test_invert_scaled_epsilon() {
+    // well-conditioned, but with a determinant below the default epsilon
+    let small = Matrix3::from_nonuniform_scale(1.0e-3f64, 2.0e-3, 1.0e-3);
+    assert!(small.determinant() < 1.0e-5);
+    assert!(small.invert().unwrap().mul_m(&small).approx_eq(&Matrix3::identity()));
+    assert!(small.is_invertible());
+
+    let small = Matrix2::new(1.0e-3f64, 2.0e-3, -2.0e-3, 1.0e-3);
+    assert!(small.invert().unwrap().mul_m(&small).approx_eq(&Matrix2::identity()));
+    assert!(small.is_invertible());
+
+    let large = matrix4::D.mul_s(1.0e4);
+    assert!(large.invert().unwrap().mul_m(&large).approx_eq(&Matrix4::identity()));
+    assert!(large.is_invertible());
+
+    // nearly parallel columns are singular relative to the size of the elements
+    let nearly_singular = Matrix2::new(1.0e6f64, 1.0e6, 1.0e6, 1.0e6 + 1.0e-6);
+    assert!(nearly_singular.invert().is_none());
+    assert!(!nearly_singular.is_invertible());
+
+    // an explicit tolerance can be given instead
+    assert!(nearly_singular.invert_with_epsilon(1.0e-3).is_some());
+    assert!(small.invert_with_epsilon(1.0).is_none());
+}
+
+#[test]
+fn test_invert_large_translation() {
+    // a translation does not make a matrix any closer to singular
+    let m = Matrix4::from_translation(&Vector3::new(1000.0f64, -250.0, 40.0));
+    assert!(m.is_invertible());
+    assert!(m.invert().unwrap().mul_m(&m).is_identity());
+
+    let m = Matrix4::from_translation(&Vector3::new(100.0f32, 0.0, 0.0));
+    assert!(m.is_invertible());
+    assert!(m.invert().unwrap().mul_m(&m).is_identity());
+
+    for &t in [1.0e5f64, 1.0e7, 1.0e10].iter() {
+        let m = Matrix4::from_translation(&Vector3::new(t, -t, 0.5 * t));
+        assert!(m.is_invertible());
+        assert!(m.invert().unwrap().mul_m(&m).is_identity());
+    }
+
+    let m = Matrix4::from_translation(&Vector3::new(1.0e5f32, 0.0, 0.0));
+    assert!(m.is_invertible());
+    assert!(m.invert().unwrap().mul_m(&m).is_identity());
+
+    let m = Matrix3::new(1.0f64,  0.0,   0.0,
+                         0.0,     1.0,   0.0,
+                         500.0,  -80.0,  1.0);
+    assert!(m.is_invertible());
+    assert!(m.invert().unwrap().mul_m(&m).is_identity());
+
+    let eye = Point3::new(1000.0f64, 2000.0, -500.0);
+    let view = Matrix4::look_at(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    let inv = view.invert().unwrap();
+    assert!(inv.mul_m(&view).approx_eq_eps(&Matrix4::identity(), &1.0e-9));
+    assert!(inv.transform_point(&Point3::new(0.0, 0.0, 0.0)).approx_eq_eps(&eye, &1.0e-9));
+
+    let eye = Point3::new(3.0e5f64, 1.0e6, -2.0e5);
+    let view = Matrix4::look_at(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    let inv = view.invert().unwrap();
+    assert!(inv.mul_m(&view).approx_eq_eps(&Matrix4::identity(), &1.0e-6));
+    assert!(inv.transform_point(&Point3::new(0.0, 0.0, 0.0)).approx_eq_eps(&eye, &1.0e-6));
+}
+
+#[test]
+fn test_mul_m_matches_definition() {
+    // each element of the product is the dot product of a row and a column
+    let (a, b) = (matrix3::D, matrix3::C);
+    let ab = a.mul_m(&b);
+    for c in range(0u, 3) {
+        for r in range(0u, 3) {
+            let mut sum = 0.0f64;
+            for k in range(0u, 3) { sum += a[k][r] * b[c][k]; }
+            assert_eq!(ab[c][r], sum);
+        }
+    }
+
+    let (a, b) = (matrix2::C, matrix2::A);
+    let ab = a.mul_m(&b);
+    for c in range(0u, 2) {
+        for r in range(0u, 2) {
+            assert_eq!(ab[c][r], a[0][r] * b[c][0] + a[1][r] * b[c][1]);
+        }
+    }
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(Matrix2::new(1i, 2, 3, 4));
+    set.insert(Matrix2::<int>::identity());
+    assert!(set.contains(&Matrix2::new(1i, 2, 3, 4)));
+    assert!(set.contains(&Matrix2::new(1i, 0, 0, 1)));
+    assert!(!set.contains(&Matrix2::new(4i, 3, 2, 1)));
+}
+
+#[test]
+fn test_default() {
+    // matrices default to the identity rather than zero
+    let m: Matrix2<f64> = Default::default();
+    assert!(m.is_identity());
+    let m: Matrix3<f64> = Default::default();
+    assert!(m.is_identity());
+    let m: Matrix4<f64> = Default::default();
+    assert!(m.is_identity());
+}
+
+#[test]
+fn test_fold() {
+    let sum_sq = |m: &Matrix4<f64>| m.fold(0.0, |acc, &x| acc + x * x);
+    let m = matrix4::A;
+    let expected = m.x.dot(&m.x) + m.y.dot(&m.y) + m.z.dot(&m.z) + m.w.dot(&m.w);
+    assert_eq!(sum_sq(&m), expected);
+
+    assert_eq!(matrix2::A.fold(0.0, |acc, &x| acc.max(x)), 4.0);
+    assert_eq!(matrix3::A.fold(0u, |acc, _| acc + 1), 9);
+}
+
+#[test]
+fn test_from_rotation_between() {
+    let pairs = [
+        (Vector3::new(1.0f64, 0.0, 0.0), Vector3::new(0.0f64, 1.0, 0.0)),
+        (Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(-2.0f64, 0.5, 1.0)),
+        (Vector3::new(0.0f64, 0.0, 2.0), Vector3::new(3.0f64, 0.0, -1.0)),
+    ];
+    for &(from, to) in pairs.iter() {
+        let m = Matrix3::from_rotation_between(&from, &to);
+        assert!(m.mul_v(&from.normalize()).approx_eq(&to.normalize()));
+        assert!(m.mul_m(&m.transpose()).is_identity());
+        assert!(m.determinant().approx_eq(&1.0));
+    }
+
+    // parallel vectors need no rotation
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert!(Matrix3::from_rotation_between(&v, &v.mul_s(2.0)).is_identity());
+
+    // antiparallel vectors are rotated by half a turn
+    for v in [Vector3::new(1.0f64, 2.0, 3.0), Vector3::unit_x(), Vector3::unit_z()].iter() {
+        let m = Matrix3::from_rotation_between(v, &-*v);
+        assert!(!m.x.x.is_nan() && !m.y.y.is_nan() && !m.z.z.is_nan());
+        assert!(m.mul_v(&v.normalize()).approx_eq(&-v.normalize()));
+        assert!(m.determinant().approx_eq(&1.0));
+    }
+
+    // nearly antiparallel vectors still get the exact shortest arc
+    let from = Vector3::new(1.0f64, 2.0, 3.0);
+    let axis = Vector3::new(3.0f64, 0.0, -1.0).normalize();
+    for &angle in [1.0e-3f64, 1.0e-4].iter() {
+        let to = Matrix3::from_axis_angle(&axis, rad(angle)).mul_v(&-from);
+        let m = Matrix3::from_rotation_between(&from, &to);
+        assert!(m.mul_v(&from.normalize()).approx_eq(&to.normalize()));
+        assert!(m.mul_m(&m.transpose()).is_identity());
+        assert!(m.determinant().approx_eq(&1.0));
+    }
+}
+
+#[test]
+fn test_to_euler() {
+    let angles = [
+        (0.0f64, 0.0f64, 0.0f64),
+        (0.5, 0.0, 0.0),
+        (0.0, -0.5, 0.0),
+        (0.0, 0.0, 1.0),
+        (1.0, 1.0, 1.0),
+        (-1.0, 0.25, 2.5),
+        (3.0, -1.5, -3.0),
+    ];
+    for &(x, y, z) in angles.iter() {
+        let (ex, ey, ez) = Matrix3::from_euler(rad(x), rad(y), rad(z)).to_euler();
+        assert!(ex.approx_eq(&rad(x)));
+        assert!(ey.approx_eq(&rad(y)));
+        assert!(ez.approx_eq(&rad(z)));
+    }
+
+    // close to the poles the angles still round-trip
+    let hpi = f64::consts::FRAC_PI_2;
+    for &d in [1.0e-3f64, 1.0e-4, 1.0e-5, 1.0e-6].iter() {
+        for &y in [hpi - d, -hpi + d].iter() {
+            let (ex, ey, ez) = Matrix3::from_euler(rad(0.3f64), rad(y), rad(0.2)).to_euler();
+            assert!(ex.approx_eq(&rad(0.3)));
+            assert!(ey.approx_eq(&rad(y)));
+            assert!(ez.approx_eq(&rad(0.2)));
+        }
+    }
+
+    // in gimbal lock the rotation is attributed to `x`, and still recreates
+    // the same matrix
+    for &y in [hpi, -hpi].iter() {
+        let m = Matrix3::from_euler(rad(0.3f64), rad(y), rad(0.2));
+        let (ex, ey, ez) = m.to_euler();
+        assert!(ey.approx_eq(&rad(y)));
+        assert_eq!(ez, rad(0.0));
+        assert!(Matrix3::from_euler(ex, ey, ez).approx_eq(&m));
+    }
+}
+
+#[test]
+fn test_decompose() {
+    let cases = [
+        (Vector3::new(2.0f64, 3.0, 4.0), Vector3::new(1.0f64, 2.0, -3.0)),
+        (Vector3::new(-2.0f64, 3.0, 4.0), Vector3::new(-1.0f64, 0.0, 5.0)),
+        (Vector3::new(1.0f64, 1.0, 1.0), Vector3::new(0.0f64, 0.0, 0.0)),
+    ];
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, 3.0), rad(0.7));
+    for &(scale, disp) in cases.iter() {
+        let mut m = rot.mul_m(&Matrix3::from_diagonal(&scale)).to_matrix4();
+        m.w = disp.extend(1.0);
+
+        let (s, q, t) = m.decompose();
+        assert!(s.approx_eq(&scale));
+        assert!(q.to_matrix3().approx_eq(&rot));
+        assert!(t.approx_eq(&disp));
+
+        let mut recomposed = q.to_matrix3().mul_m(&Matrix3::from_diagonal(&s)).to_matrix4();
+        recomposed.w = t.extend(1.0);
+        assert!(recomposed.approx_eq(&m));
+    }
+}
+
+#[test]
+fn test_is_right_handed() {
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, 3.0), rad(0.7));
+    assert!(rot.is_right_handed());
+    assert!(rot.to_matrix4().is_right_handed());
+    assert!(Matrix2::from_angle(rad(0.7f64)).is_right_handed());
+
+    let mirror = Matrix3::from_diagonal(&Vector3::new(1.0f64, -1.0, 1.0));
+    assert!(!mirror.is_right_handed());
+    assert!(!rot.mul_m(&mirror).is_right_handed());
+    assert!(!mirror.to_matrix4().is_right_handed());
+
+    // mirroring twice restores the handedness
+    assert!(mirror.mul_m(&rot).mul_m(&mirror).is_right_handed());
+}
+
+#[test]
+fn test_invert_affine() {
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, 3.0), rad(0.7));
+    let linears = [
+        Matrix3::identity(),
+        rot,
+        rot.mul_m(&Matrix3::from_diagonal(&Vector3::new(2.0, -3.0, 0.5))),
+    ];
+    let disps = [Vector3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0f64, -2.0, 3.0)];
+    for linear in linears.iter() {
+        for disp in disps.iter() {
+            let mut m = linear.to_matrix4();
+            m.w = disp.extend(1.0);
+            let inv = m.invert_affine().unwrap();
+            assert!(inv.approx_eq(&m.invert().unwrap()));
+            assert!(inv.mul_m(&m).is_identity());
+        }
+    }
+
+    let mut singular = Matrix3::from_diagonal(&Vector3::new(1.0f64, 0.0, 1.0)).to_matrix4();
+    singular.w = Vector4::new(1.0, 2.0, 3.0, 1.0);
+    assert!(singular.invert_affine().is_none());
+}
+
+#[test]
+fn test_from_diagonal() {
+    let d = Vector2::new(2.0f64, -3.0);
+    let m = Matrix2::from_diagonal(&d);
+    assert_eq!(m.diagonal(), d);
+    assert_eq!(m, Matrix2::new(2.0, 0.0, 0.0, -3.0));
+
+    let d = Vector3::new(2.0f64, -3.0, 4.0);
+    let m = Matrix3::from_diagonal(&d);
+    assert_eq!(m.diagonal(), d);
+    assert!(m.is_diagonal());
+    assert_eq!(m, Matrix3::from_nonuniform_scale(2.0, -3.0, 4.0));
+
+    let d = Vector4::new(2.0f64, -3.0, 4.0, 5.0);
+    let m = Matrix4::from_diagonal(&d);
+    assert_eq!(m.diagonal(), d);
+    assert!(m.is_diagonal());
+    assert_eq!(m.x, Vector4::new(2.0, 0.0, 0.0, 0.0));
+    assert_eq!(m.w, Vector4::new(0.0, 0.0, 0.0, 5.0));
+}
+
+#[test]
+fn test_array_round_trip() {
+    assert_eq!(matrix2::A.to_array(), [[1.0f64, 3.0], [2.0, 4.0]]);
+    assert_eq!(Matrix2::from_array(matrix2::A.to_array()), matrix2::A);
+    assert_eq!(Matrix3::from_array(matrix3::A.to_array()), matrix3::A);
+    assert_eq!(Matrix4::from_array(matrix4::A.to_array()), matrix4::A);
+
+    let a = matrix4::A.to_array();
+    assert_eq!(a[1][0], matrix4::A[1][0]);
+    assert_eq!(a[3], matrix4::A.w.to_array());
+}
+
+#[test]
+fn test_from_rotation_translation() {
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 1.0, 0.0).normalize(), rad(1.2));
+    let disp = Vector3::new(3.0f64, -1.0, 2.0);
+    let m = Matrix4::from_rotation_translation(&rot, &disp);
+
+    // the origin is moved by the translation
+    assert!(m.mul_v(&Vector4::new(0.0, 0.0, 0.0, 1.0)).approx_eq(&disp.extend(1.0)));
+    // directions are only rotated
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert!(m.mul_v(&v.extend(0.0)).approx_eq(&rot.mul_v(&v).extend(0.0)));
+    // points are rotated, then translated
+    assert!(m.mul_v(&v.extend(1.0)).approx_eq(&rot.mul_v(&v).add_v(&disp).extend(1.0)));
+
+    assert_eq!(m, Matrix4::from_translation(&disp).mul_m(&rot.to_matrix4()));
+}
+
+#[test]
+fn test_from_shear() {
+    // shear a unit square, as homogeneous 2d points
+    let m = Matrix3::from_shear_2d(0.5f64, 0.0);
+    assert_eq!(m.mul_v(&Vector3::new(0.0, 0.0, 1.0)), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(m.mul_v(&Vector3::new(1.0, 0.0, 1.0)), Vector3::new(1.0, 0.0, 1.0));
+    assert_eq!(m.mul_v(&Vector3::new(0.0, 1.0, 1.0)), Vector3::new(0.5, 1.0, 1.0));
+    assert_eq!(m.mul_v(&Vector3::new(1.0, 1.0, 1.0)), Vector3::new(1.5, 1.0, 1.0));
+
+    let m = Matrix3::from_shear_2d(0.0f64, -2.0);
+    assert_eq!(m.mul_v(&Vector3::new(1.0, 1.0, 1.0)), Vector3::new(1.0, -1.0, 1.0));
+
+    // each parameter only affects its own element
+    let m = Matrix3::from_shear(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(m.mul_v(&Vector3::unit_x()), Vector3::new(1.0, 3.0, 5.0));
+    assert_eq!(m.mul_v(&Vector3::unit_y()), Vector3::new(1.0, 1.0, 6.0));
+    assert_eq!(m.mul_v(&Vector3::unit_z()), Vector3::new(2.0, 4.0, 1.0));
+    assert_eq!(m.diagonal(), Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_normal_matrix() {
+    // the inverse transpose of a rotation is the rotation itself
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, -2.0, 0.5).normalize(), rad(0.9));
+    let m = Matrix4::from_rotation_translation(&rot, &Vector3::new(1.0, 2.0, 3.0));
+    assert!(m.normal_matrix().unwrap().approx_eq(&rot));
+
+    // normals stay perpendicular to tangents under non-uniform scaling
+    let m = Matrix4::from_nonuniform_scale(2.0f64, 0.5, 1.0);
+    let tangent = Vector3::new(1.0f64, -1.0, 0.0);
+    let normal = Vector3::new(1.0f64, 1.0, 0.0);
+    let tangent = m.truncate().mul_v(&tangent);
+    let normal = m.normal_matrix().unwrap().mul_v(&normal);
+    assert!(tangent.dot(&normal).approx_eq(&0.0));
+
+    assert!(Matrix4::from_scale(0.0f64).normal_matrix().is_none());
+}
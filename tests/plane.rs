@@ -24,7 +24,7 @@ fn test_from_points() {
     assert_eq!(Plane::from_points(Point3::new(5.0f64, 0.0f64,  5.0f64),
                                   Point3::new(5.0f64, 5.0f64,  5.0f64),
                                   Point3::new(5.0f64, 0.0f64, -1.0f64)),
-    	Some(Plane::from_abcd(-1.0f64, 0.0f64, 0.0f64, 5.0f64)));
+    	Some(Plane::from_abcd(-1.0f64, 0.0f64, 0.0f64, -5.0f64)));
 
     assert_eq!(Plane::from_points(Point3::new(0.0f64, 5.0f64, -5.0f64),
                                   Point3::new(0.0f64, 5.0f64,  0.0f64),
@@ -34,7 +34,7 @@ fn test_from_points() {
 
 #[test]
 fn test_ray_intersection() {
-    let p0 = Plane::from_abcd(1f64, 0f64, 0f64, -7f64);
+    let p0 = Plane::from_abcd(1f64, 0f64, 0f64, 7f64);
     let r0: Ray3<f64> = Ray::new(Point3::new(2f64, 3f64, 4f64), Vector3::new(1f64, 1f64, 1f64).normalize());
     assert_eq!((p0, r0).intersection(), Some(Point3::new(7f64, 8f64, 9f64)));
 
@@ -44,3 +44,46 @@ fn test_ray_intersection() {
     let r1: Ray3<f64> = Ray::new(Point3::new(0f64, 0f64, 0f64), Vector3::new(-1f64, 0f64, 0f64).normalize());
     assert_eq!((p1, r1).intersection(), None); // r1 points away from p1
 }
+
+#[test]
+fn test_distance() {
+    let p = Plane::from_point_normal(Point3::new(0.0f64, 2.0, 0.0), Vector3::unit_y());
+    assert_eq!(p.distance(&Point3::new(3.0, 5.0, -1.0)), 3.0);   // in front
+    assert_eq!(p.distance(&Point3::new(3.0, -1.0, 4.0)), -3.0);  // behind
+    assert_eq!(p.distance(&Point3::new(7.0, 2.0, 9.0)), 0.0);    // on the plane
+
+    // every point used to construct a plane lies on it
+    let (a, b, c) = (Point3::new(1.0f64, 0.0, 2.0),
+                     Point3::new(-3.0f64, 4.0, 1.0),
+                     Point3::new(2.0f64, 2.0, -5.0));
+    let p = Plane::from_points(a, b, c).unwrap();
+    assert!(p.distance(&a).approx_eq(&0.0));
+    assert!(p.distance(&b).approx_eq(&0.0));
+    assert!(p.distance(&c).approx_eq(&0.0));
+}
+
+#[test]
+fn test_normalize() {
+    let p = Plane::from_abcd(0.0f64, 0.0, 4.0, 8.0).normalize();
+    assert_eq!(p, Plane::from_abcd(0.0, 0.0, 1.0, 2.0));
+    assert_eq!(p.distance(&Point3::new(0.0, 0.0, 5.0)), 3.0);
+}
+
+#[test]
+fn test_intersect_ray() {
+    let p = Plane::from_abcd(0.0f64, 0.0, 1.0, 2.0);
+
+    // perpendicular hit
+    let r: Ray3<f64> = Ray::new(Point3::new(1.0, 1.0, 0.0), Vector3::unit_z());
+    assert_eq!(p.intersect_ray(&r), Some(2.0));
+    assert_eq!(r.at(2.0), Point3::new(1.0, 1.0, 2.0));
+
+    // parallel miss
+    let r: Ray3<f64> = Ray::new(Point3::new(1.0, 1.0, 0.0), Vector3::unit_x());
+    assert_eq!(p.intersect_ray(&r), None);
+
+    // the plane is behind the origin
+    let r: Ray3<f64> = Ray::new(Point3::new(1.0, 1.0, 5.0), Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(p.intersect_ray(&r), Some(-1.5));
+    assert_eq!((p, r).intersection(), None);
+}
@@ -17,7 +17,9 @@
 
 extern crate cgmath;
 
-use cgmath::Point3;
+use cgmath::{Point, Point2, Point3};
+use cgmath::{Vector, Vector2, Vector3};
+use cgmath::{Matrix, Matrix3, Matrix4};
 use cgmath::ApproxEq;
 
 #[test]
@@ -25,3 +27,47 @@ fn test_homogeneous() {
 	let p = Point3::new(1.0f64, 2.0f64, 3.0f64);
     assert!(p.approx_eq( &Point3::from_homogeneous( &p.to_homogeneous() ) ));
 }
+
+#[test]
+fn test_point_vector_ops() {
+    let a = Point3::new(1.0f64, 2.0, 3.0);
+    let b = Point3::new(4.0f64, 6.0, 8.0);
+    let v = Vector3::new(3.0f64, 4.0, 5.0);
+
+    // the difference between two points is a vector
+    let d: Vector3<f64> = b - a;
+    assert_eq!(d, v);
+
+    // offsetting a point by a vector gives a point
+    let p: Point3<f64> = a + v;
+    assert_eq!(p, b);
+
+    // `a + b` does not compile: two positions cannot be added together
+
+    let a = Point2::new(1.0f64, 2.0);
+    let v = Vector2::new(-3.0f64, 0.5);
+    assert_eq!((a + v) - a, v);
+}
+
+#[test]
+fn test_vector_conversion() {
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(Point3::from_vec(&v).to_vec(), v);
+    assert_eq!(Point2::from_vec(&v.truncate()), Point2::new(1.0, 2.0));
+}
+
+#[test]
+fn test_transform_point() {
+    let m = Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0));
+    let p = Point3::new(1.0f64, 1.0, 1.0);
+    assert!(m.transform_point(&p).approx_eq(&Point3::new(2.0, 3.0, 4.0)));
+
+    // vectors are not affected by translation
+    assert_eq!(m.mul_v(&Vector3::new(1.0f64, 1.0, 1.0).extend(0.0)).truncate(),
+               Vector3::new(1.0, 1.0, 1.0));
+
+    let m = Matrix3::new(1.0f64, 0.0, 0.0,
+                         0.0, 1.0, 0.0,
+                         5.0, -1.0, 1.0);
+    assert!(m.transform_point(&Point2::new(1.0f64, 1.0)).approx_eq(&Point2::new(6.0, 0.0)));
+}
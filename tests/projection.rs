@@ -15,7 +15,8 @@
 
 extern crate cgmath;
 
-use cgmath::{Vector4, ortho, Matrix, Matrix4, Vector};
+use cgmath::{Vector4, ortho, frustum, perspective, deg, Matrix, Matrix4, Vector};
+use cgmath::ApproxEq;
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +68,48 @@ fn test_ortho_translate() {
     let orig = o.mul_v(&vec_orig);
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+/// Transform a point in eye space into normalized device coordinates
+fn to_ndc(proj: &Matrix4<f64>, x: f64, y: f64, z: f64) -> Vector4<f64> {
+    let clip = proj.mul_v(&Vector4::new(x, y, z, 1.0));
+    clip.div_s(clip.w)
+}
+
+#[test]
+fn test_perspective_depth_range() {
+    let p: Matrix4<f64> = perspective(deg(60.0f64), 1.5, 0.5, 100.0);
+    assert!(to_ndc(&p, 0.0, 0.0, -0.5).z.approx_eq(&-1.0));
+    assert!(to_ndc(&p, 0.0, 0.0, -100.0).z.approx_eq(&1.0));
+}
+
+#[test]
+fn test_frustum_depth_range() {
+    let p: Matrix4<f64> = frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    assert!(to_ndc(&p, 0.0, 0.0, -1.0).z.approx_eq(&-1.0));
+    assert!(to_ndc(&p, 0.0, 0.0, -10.0).z.approx_eq(&1.0));
+
+    // the corners of the near plane map to the corners of clip space
+    assert!(to_ndc(&p, -1.0, -1.0, -1.0).approx_eq(&Vector4::new(-1.0, -1.0, -1.0, 1.0)));
+    assert!(to_ndc(&p, 1.0, 1.0, -1.0).approx_eq(&Vector4::new(1.0, 1.0, -1.0, 1.0)));
+}
+
+#[test]
+fn test_ortho_depth_range() {
+    let o: Matrix4<f64> = ortho(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    assert!(to_ndc(&o, 0.0, 0.0, -1.0).z.approx_eq(&-1.0));
+    assert!(to_ndc(&o, 0.0, 0.0, -10.0).z.approx_eq(&1.0));
+}
+
+#[test]
+fn test_degenerate_depth_range() {
+    // an empty depth range maps everything to a depth of zero instead of panicking
+    let p: Matrix4<f64> = perspective(deg(60.0f64), 1.0, 1.0, 1.0);
+    assert_eq!(to_ndc(&p, 0.0, 0.0, -1.0).z, 0.0);
+    assert_eq!(to_ndc(&p, 0.0, 0.0, -5.0).z, 0.0);
+
+    let f: Matrix4<f64> = frustum(-1.0, 1.0, -1.0, 1.0, 2.0, 2.0);
+    assert_eq!(to_ndc(&f, 0.0, 0.0, -2.0).z, 0.0);
+
+    let o: Matrix4<f64> = ortho(-1.0, 1.0, -1.0, 1.0, 2.0, 2.0);
+    assert_eq!(to_ndc(&o, 0.0, 0.0, -2.0).z, 0.0);
+}
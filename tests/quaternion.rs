@@ -17,13 +17,17 @@
 
 extern crate cgmath;
 
-use cgmath::{ToMatrix4, ToMatrix3};
-use cgmath::Quaternion;
+use cgmath::{ToMatrix4, ToMatrix3, ToQuaternion};
+use cgmath::{Matrix3, Quaternion, Vector3};
 
 use cgmath::{Rad, rad, ApproxEq};
-use cgmath::Rotation3;
+use cgmath::{Matrix, Rotation, Rotation3};
+use cgmath::{Vector, EuclideanVector};
 
 use std::f32;
+use std::f64;
+use std::num::Float;
+use std::rand::IsaacRng;
 
 #[test]
 fn to_matrix4()
@@ -78,10 +82,267 @@ fn to_and_from_quaternion()
     let xzy_n1: Quaternion<f32> = Rotation3::from_euler(rad(-1f32), rad(-1f32), rad(-1f32));
     eq((rad(-1f32), rad(-1f32), rad(-1f32)), xzy_n1.to_euler());
 
+    // in gimbal lock the rotation around `z` is attributed to `x`
     let xzy_hp: Quaternion<f32> = Rotation3::from_euler(rad(0f32), rad(hpi), rad(1f32));
-    eq((rad(0f32), rad(hpi), rad(1f32)), xzy_hp.to_euler());
+    eq((rad(-1f32), rad(hpi), rad(0f32)), xzy_hp.to_euler());
 
     let xzy_nhp: Quaternion<f32> = Rotation3::from_euler(rad(0f32), rad(-hpi), rad(1f32));
-    eq((rad(0f32), rad(-hpi), rad(1f32)), xzy_nhp.to_euler());
+    eq((rad(1f32), rad(-hpi), rad(0f32)), xzy_nhp.to_euler());
 
 }
+
+#[test]
+fn from_axis_angle()
+{
+    let axes = [Vector3::new(1f32, 0f32, 0f32),
+                Vector3::new(0f32, 1f32, 0f32),
+                Vector3::new(1f32, 2f32, 3f32),
+                Vector3::new(-4f32, 0.5f32, 2f32)];
+    let angles = [0.3f32, -1f32, 2.5f32];
+
+    for axis in axes.iter() {
+        for &angle in angles.iter() {
+            let q: Quaternion<f32> = Rotation3::from_axis_angle(axis, rad(angle));
+            assert!(q.to_matrix3().approx_eq(&Matrix3::from_axis_angle(axis, rad(angle))));
+            assert!(q.magnitude().approx_eq(&1f32));
+        }
+    }
+}
+
+#[test]
+fn slerp()
+{
+    let a: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(0f32, 0f32, 1f32), rad(0.2f32));
+    let b: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(1f32, 1f32, 0f32), rad(2f32));
+
+    assert!(a.slerp(&b, 0f32).approx_eq(&a));
+    assert!(a.slerp(&b, 1f32).approx_eq(&b));
+    assert!(a.slerp(&b, 0.5f32).magnitude().approx_eq(&1f32));
+
+    // nearly parallel quaternions fall back to nlerp
+    let c: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(0f32, 0f32, 1f32), rad(0.201f32));
+    assert!(a.slerp(&c, 1f32).approx_eq(&c));
+    assert!(a.slerp(&c, 0.5f32).magnitude().approx_eq(&1f32));
+}
+
+#[test]
+fn slerp_shortest_path()
+{
+    let a: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(0f32, 0f32, 1f32), rad(0.5f32));
+    let b: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(0f32, 0f32, 1f32), rad(1.5f32));
+
+    // `-b` is the same rotation as `b`, so the midpoint must be the same too
+    let expected: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(0f32, 0f32, 1f32), rad(1f32));
+    assert!(a.slerp(&b, 0.5f32).approx_eq(&expected));
+    assert!(a.slerp(&-b, 0.5f32).approx_eq(&expected));
+}
+
+#[test]
+fn mul_q()
+{
+    let q1: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(1f32, 2f32, 3f32), rad(0.7f32));
+    let q2: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(-1f32, 0f32, 1f32), rad(-1.2f32));
+
+    let product = q1.mul_q(&q2);
+    assert!(product.to_matrix3().approx_eq(&q1.to_matrix3().mul_m(&q2.to_matrix3())));
+
+    let mut q = q1;
+    q.mul_self_q(&q2);
+    assert!(q.approx_eq(&product));
+
+    let mut q = q1;
+    q.concat_self(&q2);
+    assert!(q.approx_eq(&product));
+}
+
+#[test]
+fn mul_v()
+{
+    let q: Quaternion<f32> = Rotation3::from_axis_angle(&Vector3::new(1f32, 2f32, 3f32), rad(0.7f32));
+    let v = Vector3::new(3f32, -1f32, 0.5f32);
+    assert!(q.mul_v(&v).approx_eq(&q.to_matrix3().mul_v(&v)));
+}
+
+#[test]
+fn invert()
+{
+    let q = Quaternion::new(2f32, -1f32, 0.5f32, 3f32);
+    assert!(q.mul_q(&q.checked_invert().unwrap()).approx_eq(&Quaternion::identity()));
+    assert!(q.checked_invert().unwrap().mul_q(&q).approx_eq(&Quaternion::identity()));
+
+    // the inverse of a unit quaternion is its conjugate
+    let unit = q.normalize();
+    assert!(unit.magnitude().approx_eq(&1f32));
+    assert!(unit.checked_invert().unwrap().approx_eq(&unit.conjugate()));
+
+    assert!(Quaternion::<f32>::zero().checked_invert().is_none());
+
+    // the trait method still returns the rotation itself
+    let r: Quaternion<f32> = q.invert();
+    assert!(r.approx_eq(&q.checked_invert().unwrap()));
+
+    // small quaternions are still invertible
+    let small = Quaternion::new(1.0e-3f64, -2.0e-3, 0.5e-3, 1.0e-3);
+    assert!(small.magnitude2() < 1.0e-5);
+    assert!(small.mul_q(&small.checked_invert().unwrap()).approx_eq(&Quaternion::identity()));
+}
+
+#[test]
+fn to_and_from_matrix3()
+{
+    // include rotations with a negative trace, which take the other branches
+    let axes = [Vector3::new(1f32, 0f32, 0f32),
+                Vector3::new(0f32, 1f32, 0f32),
+                Vector3::new(0f32, 0f32, 1f32),
+                Vector3::new(1f32, 2f32, -3f32)];
+    let angles = [0.3f32, 2.5f32, 3f32, -2.8f32];
+
+    for axis in axes.iter() {
+        for &angle in angles.iter() {
+            let m = Matrix3::from_axis_angle(axis, rad(angle));
+            assert!(m.to_quaternion().to_matrix3().approx_eq(&m));
+            assert!(m.to_quaternion().to_matrix4().approx_eq(&m.to_matrix4()));
+        }
+    }
+}
+
+#[test]
+fn test_rand_rotation() {
+    let mut rng = IsaacRng::new_unseeded();
+    let n = 10000u;
+    let mut sum = Vector3::new(0.0f64, 0.0, 0.0);
+    for _ in range(0, n) {
+        let q: Quaternion<f64> = Quaternion::rand_rotation(&mut rng);
+        assert!(q.magnitude().approx_eq(&1.0));
+        sum.add_self_v(&q.mul_v(&Vector3::unit_x()));
+    }
+    // uniformly distributed rotations scatter a fixed vector evenly
+    assert!(sum.div_s(n as f64).length() < 0.05);
+}
+
+#[test]
+fn test_default() {
+    let q: Quaternion<f64> = Default::default();
+    assert_eq!(q, Quaternion::identity());
+    assert!(q.mul_v(&Vector3::unit_x()).approx_eq(&Vector3::unit_x()));
+}
+
+#[test]
+fn test_from_rotation_between() {
+    let pairs = [
+        (Vector3::new(1.0f64, 0.0, 0.0), Vector3::new(0.0f64, 1.0, 0.0)),
+        (Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(-2.0f64, 0.5, 1.0)),
+        (Vector3::new(0.0f64, 0.0, 2.0), Vector3::new(3.0f64, 0.0, -1.0)),
+        (Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(-1.0f64, -2.0, -3.0)),
+    ];
+    for &(from, to) in pairs.iter() {
+        let q = Quaternion::from_rotation_between(&from, &to);
+        assert!(q.magnitude().approx_eq(&1.0));
+        assert!(q.mul_v(&from.normalize()).approx_eq(&to.normalize()));
+        assert!(q.to_matrix3().approx_eq(&Matrix3::from_rotation_between(&from, &to)));
+    }
+
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert!(Quaternion::from_rotation_between(&v, &v).approx_eq(&Quaternion::identity()));
+
+    // nearly antiparallel vectors still get the exact shortest arc
+    let from = Vector3::new(1.0f64, 2.0, 3.0);
+    let axis = Vector3::new(3.0f64, 0.0, -1.0).normalize();
+    for &angle in [1.0e-3f64, 1.0e-4].iter() {
+        let to = Matrix3::from_axis_angle(&axis, rad(angle)).mul_v(&-from);
+        let q = Quaternion::from_rotation_between(&from, &to);
+        assert!(q.magnitude().approx_eq(&1.0));
+        assert!(q.mul_v(&from.normalize()).approx_eq(&to.normalize()));
+    }
+}
+
+#[test]
+fn test_nlerp() {
+    let a = Quaternion::from_sv(0.5f64.sqrt(), Vector3::new(0.5f64.sqrt(), 0.0, 0.0));
+    let b = Quaternion::from_sv(0.5f64.sqrt(), Vector3::new(0.0, 0.5f64.sqrt(), 0.0));
+    assert!(a.nlerp(&b, 0.0).approx_eq(&a));
+    assert!(a.nlerp(&b, 1.0).approx_eq(&b));
+    for i in range(1u, 10) {
+        let t = i as f64 / 10.0;
+        assert!(a.nlerp(&b, t).magnitude().approx_eq(&1.0));
+    }
+
+    // the shorter arc is taken when the quaternions point apart
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    let c = a.nlerp(&-b, 0.5);
+    assert!(c.approx_eq(&a.nlerp(&b, 0.5)));
+    assert!(a.nlerp(&-b, 1.0).mul_v(&v).approx_eq(&b.mul_v(&v)));
+}
+
+#[test]
+fn test_to_axis_angle() {
+    let rotations = [
+        (Vector3::new(1.0f64, 0.0, 0.0), 0.5f64),
+        (Vector3::new(0.0f64, 1.0, 0.0), 2.0),
+        (Vector3::new(1.0f64, 2.0, -3.0).normalize(), 1.0),
+        (Vector3::new(-1.0f64, 1.0, 1.0).normalize(), 3.0),
+    ];
+    for &(axis, angle) in rotations.iter() {
+        let q: Quaternion<f64> = Rotation3::from_axis_angle(&axis, rad(angle));
+        let (a, theta) = q.to_axis_angle();
+        assert!(a.approx_eq(&axis));
+        assert!(theta.approx_eq(&rad(angle)));
+    }
+
+    let (a, theta) = Quaternion::<f64>::identity().to_axis_angle();
+    assert_eq!(a, Vector3::unit_x());
+    assert_eq!(theta, rad(0.0));
+}
+
+#[test]
+fn test_approx_eq_rotation() {
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(&Vector3::new(1.0, 2.0, 3.0).normalize(), rad(0.7));
+    let v = Vector3::new(-2.0f64, 0.5, 1.0);
+
+    // a quaternion and its negation are different values...
+    assert!(q.approx_eq(&q));
+    assert!(!q.approx_eq(&-q));
+    // ...but the same rotation
+    assert!(q.mul_v(&v).approx_eq(&(-q).mul_v(&v)));
+    assert!(q.approx_eq_rotation(&q));
+    assert!(q.approx_eq_rotation(&-q));
+    assert!((-q).approx_eq_rotation(&q));
+
+    let r: Quaternion<f64> = Rotation3::from_axis_angle(&Vector3::new(1.0, 2.0, 3.0).normalize(), rad(0.8));
+    assert!(!q.approx_eq_rotation(&r));
+    assert!(!q.approx_eq_rotation(&-r));
+}
+
+#[test]
+fn test_euler_matches_matrix() {
+    let hpi = f64::consts::FRAC_PI_2;
+    let angles = [
+        (0.5f64, 0.0f64, 0.0f64),
+        (1.0, 1.0, 1.0),
+        (-1.0, 0.25, 2.5),
+        (0.3, hpi - 0.01, 0.2),
+        (0.3, -hpi + 0.01, 0.2),
+        (0.3, hpi - 1.0e-3, 0.2),
+        (0.3, -hpi + 1.0e-3, 0.2),
+        (0.3, hpi - 1.0e-5, 0.2),
+        (0.3, hpi, 0.2),
+        (0.3, -hpi, 0.2),
+    ];
+    for &(x, y, z) in angles.iter() {
+        let (x, y, z) = (rad(x), rad(y), rad(z));
+        let q: Quaternion<f64> = Rotation3::from_euler(x, y, z);
+        let m = Matrix3::from_euler(x, y, z);
+        assert!(q.to_matrix3().approx_eq(&m));
+
+        let (qx, qy, qz) = q.to_euler();
+        let (mx, my, mz) = m.to_euler();
+        assert!(qx.approx_eq(&mx));
+        assert!(qy.approx_eq(&my));
+        assert!(qz.approx_eq(&mz));
+
+        let (cx, cy, cz) = m.to_quaternion().to_euler();
+        assert!(cx.approx_eq(&mx));
+        assert!(cy.approx_eq(&my));
+        assert!(cz.approx_eq(&mz));
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_at() {
+    let origin = Point3::new(1.0f64, 2.0, 3.0);
+    let dir = Vector3::new(0.0f64, 0.0, -1.0);
+    let r = Ray::new(origin, dir);
+    assert_eq!(r.at(0.0), origin);
+    assert_eq!(r.at(1.0), origin.add_v(&dir));
+    assert_eq!(r.at(-2.0), Point3::new(1.0, 2.0, 5.0));
+
+    let r = Ray::new(Point2::new(1.0f64, 1.0), Vector2::unit_x());
+    assert_eq!(r.at(0.0), Point2::new(1.0, 1.0));
+    assert_eq!(r.at(1.0), Point2::new(2.0, 1.0));
+}
+
+#[test]
+fn test_new_normalized() {
+    let origin = Point3::new(1.0f64, 2.0, 3.0);
+    let r: Ray3<f64> = Ray::new_normalized(origin, Vector3::new(0.0, 3.0, 4.0));
+    assert!(r.direction.approx_eq(&Vector3::new(0.0, 0.6, 0.8)));
+    assert!(r.at(5.0).approx_eq(&Point3::new(1.0, 5.0, 7.0)));
+
+    let r: Ray2<f64> = Ray::new_normalized(Point2::new(0.0, 0.0), Vector2::new(0.0, -2.0));
+    assert!(r.at(1.0).approx_eq(&Point2::new(0.0, -1.0)));
+}
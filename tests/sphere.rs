@@ -32,3 +32,40 @@ fn test_intersection() {
     assert_eq!((sphere,r2).intersection(), Some(Point3::new(1f64, 0f64, 0f64)));
     assert_eq!((sphere,r3).intersection(), None);
 }
+
+#[test]
+fn test_contains() {
+    let sphere = Sphere {center: Point3::new(1f64, 2f64, 3f64), radius: 2f64};
+    assert!(sphere.contains(&Point3::new(1f64, 2f64, 3f64)));
+    assert!(sphere.contains(&Point3::new(2f64, 3f64, 4f64)));
+    assert!(sphere.contains(&Point3::new(3f64, 2f64, 3f64)));    // on the surface
+    assert!(!sphere.contains(&Point3::new(3f64, 3f64, 3f64)));
+}
+
+#[test]
+fn test_intersect_ray() {
+    let sphere = Sphere {center: Point3::new(0f64, 0f64, 0f64), radius: 1f64};
+
+    // two intersections, the nearest is returned
+    let r = Ray::new(Point3::new(0f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -1f64));
+    assert_eq!(sphere.intersect_ray(&r), Some(4f64));
+
+    // the direction does not have to be normalized
+    let r = Ray::new(Point3::new(0f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -2f64));
+    assert_eq!(sphere.intersect_ray(&r), Some(2f64));
+
+    // tangent
+    let r = Ray::new(Point3::new(1f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -1f64));
+    assert_eq!(sphere.intersect_ray(&r), Some(5f64));
+
+    // starting inside, hits on the way out
+    let r = Ray::new(Point3::new(0f64, 0.5f64, 0f64), Vector3::new(0f64, 1f64, 0f64));
+    assert_eq!(sphere.intersect_ray(&r), Some(0.5f64));
+    assert_eq!((sphere, r).intersection(), Some(Point3::new(0f64, 1f64, 0f64)));
+
+    // missing, and pointing away
+    let r = Ray::new(Point3::new(2f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -1f64));
+    assert_eq!(sphere.intersect_ray(&r), None);
+    let r = Ray::new(Point3::new(0f64, 0f64, 5f64), Vector3::new(0f64, 0f64, 1f64));
+    assert_eq!(sphere.intersect_ray(&r), None);
+}
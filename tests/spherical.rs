@@ -0,0 +1,61 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+#[test]
+fn test_to_vec3() {
+    let s = Spherical::new(2.0f64, rad(f64::consts::FRAC_PI_2), rad(0.0));
+    assert!(s.to_vec3().approx_eq(&Vector3::new(2.0, 0.0, 0.0)));
+
+    let s = Spherical::new(1.0f64, rad(f64::consts::FRAC_PI_2), rad(f64::consts::FRAC_PI_2));
+    assert!(s.to_vec3().approx_eq(&Vector3::new(0.0, 1.0, 0.0)));
+
+    let s = Spherical::new(3.0f64, rad(0.0), rad(1.0));
+    assert!(s.to_vec3().approx_eq(&Vector3::new(0.0, 0.0, 3.0)));
+}
+
+#[test]
+fn test_round_trip() {
+    let points = [Vector3::new(1.0f64, 2.0, 3.0),
+                  Vector3::new(-4.0f64, 0.5, -2.0),
+                  Vector3::new(0.0f64, -1.0, 0.0),
+                  Vector3::new(-1.0f64, -1.0, 1.0)];
+    for v in points.iter() {
+        let s = v.to_spherical();
+        assert!(s.radius.approx_eq(&v.length()));
+        assert!(s.to_vec3().approx_eq(v));
+    }
+}
+
+#[test]
+fn test_poles() {
+    // on the axis the azimuth is undefined, and is zero rather than NaN
+    let s = Vector3::new(0.0f64, 0.0, 5.0).to_spherical();
+    assert_eq!(s, Spherical::new(5.0, rad(0.0), rad(0.0)));
+    assert!(s.to_vec3().approx_eq(&Vector3::new(0.0, 0.0, 5.0)));
+
+    let s = Vector3::new(0.0f64, 0.0, -5.0).to_spherical();
+    assert!(s.approx_eq(&Spherical::new(5.0, rad(f64::consts::PI), rad(0.0))));
+    assert!(s.to_vec3().approx_eq(&Vector3::new(0.0, 0.0, -5.0)));
+
+    let s = Vector3::new(0.0f64, 0.0, 0.0).to_spherical();
+    assert_eq!(s, Spherical::new(0.0, rad(0.0), rad(0.0)));
+}
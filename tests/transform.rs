@@ -42,3 +42,89 @@ fn test_look_at() {
 	let view_point = Point3::new(0.0f64, 1.0f64, 5.0f64);
 	assert!( t.transform_point(&point).approx_eq(&view_point) );
 }
+
+#[test]
+fn test_concat() {
+    let a = Decomposed {
+        scale: 1.5f64,
+        rot: Quaternion::new(0.5f64, 0.5, 0.5, 0.5),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    let b = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::new(0.6f64, 0.8, 0.0, 0.0),
+        disp: Vector3::new(1.0f64, 2.0, 3.0),
+    };
+    let ab = a.concat(&b);
+    assert!(ab.to_matrix4().approx_eq(&a.to_matrix4().mul_m(&b.to_matrix4())));
+
+    let p = Point3::new(1.0f64, -2.0, 0.5);
+    assert!(ab.transform_point(&p).approx_eq(&a.transform_point(&b.transform_point(&p))));
+    assert!(ab.transform_point(&p).approx_eq(&ab.to_matrix4().transform_point(&p)));
+
+    let v = Vector3::new(1.0f64, -2.0, 0.5);
+    assert!(ab.transform_vector(&v).approx_eq(&a.transform_vector(&b.transform_vector(&v))));
+}
+
+#[test]
+fn test_invert_concat() {
+    let t = Decomposed {
+        scale: 1.5f64,
+        rot: Quaternion::new(0.5f64, 0.5, 0.5, 0.5),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    let ti = t.invert().expect("Expected successful inversion");
+    assert!(t.concat(&ti).to_matrix4().approx_eq(&Matrix4::identity()));
+    assert!(ti.concat(&t).to_matrix4().approx_eq(&Matrix4::identity()));
+
+    let p = Point3::new(1.0f64, -2.0, 0.5);
+    assert!(ti.transform_point(&t.transform_point(&p)).approx_eq(&p));
+
+    let t = Decomposed {
+        scale: 0.0f64,
+        rot: Quaternion::new(0.5f64, 0.5, 0.5, 0.5),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    assert!(t.invert().is_none());
+}
+
+#[test]
+fn test_affine_matrix2() {
+    let p = Point2::new(1.0f64, 2.0);
+    let v = Vector2::new(1.0f64, 2.0);
+
+    let t = AffineMatrix2::from_translation(&Vector2::new(3.0f64, -1.0));
+    assert!(t.transform_point(&p).approx_eq(&Point2::new(4.0, 1.0)));
+    assert!(t.transform_vector(&v).approx_eq(&v));
+
+    let r = AffineMatrix2::from_angle(deg(90.0f64).to_rad());
+    assert!(r.transform_point(&p).approx_eq(&Point2::new(-2.0, 1.0)));
+    assert!(r.transform_vector(&v).approx_eq(&Vector2::new(-2.0, 1.0)));
+
+    let s = AffineMatrix2::from_scale(2.0f64);
+    assert!(s.transform_point(&p).approx_eq(&Point2::new(2.0, 4.0)));
+
+    // translate, then rotate
+    let rt = r.concat(&t);
+    let expected = r.to_matrix3().mul_m(&t.to_matrix3()).mul_v(&p.to_homogeneous());
+    assert!(rt.transform_point(&p).approx_eq(&Point2::from_homogeneous(&expected)));
+    assert!(rt.transform_point(&p).approx_eq(&Point2::new(-1.0, 4.0)));
+    assert!(rt.invert().unwrap().transform_point(&rt.transform_point(&p)).approx_eq(&p));
+}
+
+#[test]
+fn test_affine_invert_large_translation() {
+    let eye = Point3::new(1000.0f64, 2000.0, -500.0);
+    let t: AffineMatrix3<f64> = Transform::look_at(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    let ti = t.invert().expect("Expected successful inversion");
+    assert!(ti.transform_point(&Point3::new(0.0, 0.0, 0.0)).approx_eq_eps(&eye, &1.0e-9));
+
+    let eye = Point3::new(2.0e5f64, -1.0e6, 5.0e5);
+    let t: AffineMatrix3<f64> = Transform::look_at(&eye, &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    let ti = t.invert().expect("Expected successful inversion");
+    assert!(ti.transform_point(&Point3::new(0.0, 0.0, 0.0)).approx_eq_eps(&eye, &1.0e-6));
+
+    let t = AffineMatrix2::from_translation(&Vector2::new(500.0f64, -80.0));
+    let ti = t.invert().expect("Expected successful inversion");
+    assert!(ti.transform_point(&Point2::new(500.0, -80.0)).approx_eq(&Point2::new(0.0, 0.0)));
+}
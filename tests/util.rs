@@ -0,0 +1,73 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(globs)]
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_clamp() {
+    assert_eq!(clamp(-2i, 0, 10), 0);
+    assert_eq!(clamp(5i, 0, 10), 5);
+    assert_eq!(clamp(12i, 0, 10), 10);
+    assert_eq!(clamp(-0.5f64, 0.0, 1.0), 0.0);
+    assert_eq!(clamp(0.5f64, 0.0, 1.0), 0.5);
+    assert_eq!(clamp(1.5f64, 0.0, 1.0), 1.0);
+}
+
+#[test]
+fn test_lerp() {
+    assert_eq!(lerp(2.0f64, 6.0, 0.0), 2.0);
+    assert_eq!(lerp(2.0f64, 6.0, 0.5), 4.0);
+    assert_eq!(lerp(2.0f64, 6.0, 1.0), 6.0);
+
+    // the free function and the `Lerp` method are both in scope, and agree
+    assert_eq!(lerp(2.0f64, 6.0, 0.25), 2.0f64.lerp(&6.0, 0.25));
+}
+
+#[test]
+fn test_step() {
+    assert_eq!(step(1.0f64, 0.5), 0.0);
+    assert_eq!(step(1.0f64, 1.0), 1.0);
+    assert_eq!(step(1.0f64, 1.5), 1.0);
+}
+
+#[test]
+fn test_smoothstep() {
+    assert_eq!(smoothstep(0.0f64, 1.0, 0.0), 0.0);
+    assert_eq!(smoothstep(0.0f64, 1.0, 1.0), 1.0);
+    assert_eq!(smoothstep(0.0f64, 1.0, 0.5), 0.5);
+    assert_eq!(smoothstep(0.0f64, 1.0, 0.25), 0.15625);
+    assert_eq!(smoothstep(2.0f64, 4.0, 3.0), 0.5);
+
+    // values outside of the edges are clamped
+    assert_eq!(smoothstep(0.0f64, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0f64, 1.0, 2.0), 1.0);
+}
+
+#[test]
+fn test_smootherstep() {
+    assert_eq!(smootherstep(0.0f64, 1.0, 0.0), 0.0);
+    assert_eq!(smootherstep(0.0f64, 1.0, 1.0), 1.0);
+    assert_eq!(smootherstep(0.0f64, 1.0, 0.5), 0.5);
+    assert_eq!(smootherstep(0.0f64, 1.0, 0.25), 0.103515625);
+    assert_eq!(smootherstep(2.0f64, 4.0, 3.0), 0.5);
+
+    // values outside of the edges are clamped
+    assert_eq!(smootherstep(0.0f64, 1.0, -1.0), 0.0);
+    assert_eq!(smootherstep(0.0f64, 1.0, 2.0), 1.0);
+}
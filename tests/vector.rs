@@ -22,7 +22,9 @@ extern crate cgmath;
 
 use cgmath::*;
 use std::f64;
+use std::i32;
 use std::num::{Float, FloatMath};
+use std::rand::{IsaacRng, Rng};
 
 #[test]
 fn test_from_value() {
@@ -31,6 +33,16 @@ fn test_from_value() {
     assert_eq!(Vector4::from_value(76.5f64), Vector4::new(76.5f64, 76.5f64, 76.5f64, 76.5f64));
 }
 
+#[test]
+fn test_array_round_trip() {
+    assert_eq!(Vector2::new(1i, 2).to_array(), [1i, 2]);
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).to_array(), [1.0f64, 2.0, 3.0]);
+    assert_eq!(Vector4::from_array([1i, 2, 3, 4]), Vector4::new(1i, 2, 3, 4));
+
+    let v = Vector3::new(-1.5f64, 0.0, 7.25);
+    assert_eq!(Vector3::from_array(v.to_array()), v);
+}
+
 #[test]
 fn test_dot() {
     assert_eq!(Vector2::new(1i, 2i).dot(&Vector2::new(3i, 4i)), 11i);
@@ -157,6 +169,41 @@ fn test_angle() {
     assert!(Vector4::new(-1.0f64, 0.0f64, -1.0f64, 0.0f64).angle(&Vector4::new(0.0f64, 1.0f64, 0.0f64, 1.0f64)).approx_eq( &rad(f64::consts::FRAC_PI_2) ));
 }
 
+#[test]
+fn test_angle_limits() {
+    let pi = rad(f64::consts::PI);
+
+    let a = Vector2::new(1.0f64, 2.0);
+    assert!(a.angle(&a).approx_eq(&rad(0.0)));
+    assert!(a.angle(&-a).approx_eq(&pi));
+
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    assert!(a.angle(&a).approx_eq(&rad(0.0)));
+    assert!(a.angle(&-a).approx_eq(&pi));
+
+    // these would round to just outside [-1, 1] without clamping
+    let a = Vector4::new(0.1f64, 0.2, 0.3, 0.7);
+    assert!(a.angle(&a).approx_eq(&rad(0.0)));
+    assert!(a.angle(&-a).approx_eq(&pi));
+    assert!(!a.angle(&a.mul_s(3.0)).s.is_nan());
+}
+
+#[test]
+fn test_distance() {
+    let (a, b) = (Vector2::new(1.0f64, 1.0), Vector2::new(4.0f64, 5.0));
+    assert_eq!(a.distance2(&b), 25.0);
+    assert_eq!(a.distance(&b), 5.0);
+    assert_eq!(b.distance(&a), 5.0);
+
+    let (a, b) = (Vector3::new(1.0f64, 1.0, 1.0), Vector3::new(3.0f64, 4.0, 7.0));
+    assert_eq!(a.distance2(&b), 49.0);
+    assert_eq!(a.distance(&b), 7.0);
+
+    let a = Vector4::new(1.0f64, 2.0, 4.0, 10.0);
+    assert_eq!(a.distance(&a), 0.0);
+    assert_eq!(a.distance(&Vector4::from_value(0.0)), 11.0);
+}
+
 #[test]
 fn test_normalize() {
     // TODO: test normalize_to, normalize_sel.0, and normalize_self_to
@@ -177,3 +224,614 @@ fn test_cast() {
     assert_approx_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast(), Vector3::new(1.0f32, 2.4, -3.13));
     assert_approx_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
 }
+
+#[test]
+fn test_lerp() {
+    let (a, b) = (Vector2::new(1.0f64, -2.0), Vector2::new(3.0f64, 6.0));
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), Vector2::new(2.0, 2.0));
+
+    let (a, b) = (Vector3::new(1.0f64, -2.0, 0.0), Vector3::new(3.0f64, 6.0, -4.0));
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), Vector3::new(2.0, 2.0, -2.0));
+
+    let (a, b) = (Vector4::new(1.0f64, -2.0, 0.0, 8.0), Vector4::new(3.0f64, 6.0, -4.0, 8.0));
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), Vector4::new(2.0, 2.0, -2.0, 8.0));
+
+    let mut c = a;
+    c.lerp_self(&b, 0.5);
+    assert_eq!(c, a.lerp(&b, 0.5));
+}
+
+#[test]
+fn test_lerp_through_vector_bound() {
+    fn halfway<V: Vector<f64>>(a: &V, b: &V) -> V { a.lerp(b, 0.5) }
+    assert_eq!(halfway(&Vector2::new(1.0, -2.0), &Vector2::new(3.0, 6.0)), Vector2::new(2.0, 2.0));
+    assert_eq!(halfway(&Vector3::new(0.0, 0.0, 4.0), &Vector3::new(2.0, 2.0, 0.0)), Vector3::new(1.0, 1.0, 2.0));
+    assert_eq!(halfway(&Vector4::new(0.0, 2.0, 4.0, 6.0), &Vector4::zero()), Vector4::new(0.0, 1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_reflect() {
+    // a 45 degree incoming vector bounces off at 45 degrees
+    assert_eq!(Vector2::new(1.0f64, -1.0).reflect(&Vector2::unit_y()), Vector2::new(1.0, 1.0));
+    assert_eq!(Vector3::new(1.0f64, 2.0, -1.0).reflect(&Vector3::unit_z()), Vector3::new(1.0, 2.0, 1.0));
+    assert_eq!(Vector4::new(-1.0f64, 0.0, 0.0, 1.0).reflect(&Vector4::unit_x()), Vector4::new(1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_refract() {
+    // head on, the direction does not change
+    let down = Vector2::new(0.0f64, -1.0);
+    assert!(down.refract(&Vector2::unit_y(), 0.7).approx_eq(&down));
+
+    // entering a denser medium bends the vector towards the normal
+    let incoming = Vector3::new(1.0f64, 0.0, -1.0).normalize();
+    let refracted = incoming.refract(&Vector3::unit_z(), 1.0 / 1.5);
+    assert!(refracted.length().approx_eq(&1.0));
+    assert!(refracted.x > 0.0 && refracted.x < incoming.x);
+    assert!(refracted.z < 0.0);
+
+    // grazing angles leaving a denser medium are totally internally reflected
+    let grazing = Vector3::new(1.0f64, 0.0, -0.2).normalize();
+    assert_eq!(grazing.refract(&Vector3::unit_z(), 1.5), Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_project_on() {
+    let (a, b) = (Vector2::new(3.0f64, 4.0), Vector2::new(2.0f64, 0.0));
+    assert_eq!(a.project_on(&b), Vector2::new(3.0, 0.0));
+    assert_eq!(a.reject_from(&b), Vector2::new(0.0, 4.0));
+
+    let (a, b) = (Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(-1.0f64, 0.5, 2.0));
+    assert!(a.project_on(&b).add_v(&a.reject_from(&b)).approx_eq(&a));
+    assert!(a.reject_from(&b).is_perpendicular(&b));
+    assert!(a.project_on(&b).cross(&b).approx_eq(&Vector3::new(0.0, 0.0, 0.0)));
+
+    let (a, b) = (Vector4::new(1.0f64, 2.0, 3.0, 4.0), Vector4::new(0.0f64, -1.0, 1.0, 2.0));
+    assert!(a.project_on(&b).add_v(&a.reject_from(&b)).approx_eq(&a));
+    assert!(a.reject_from(&b).is_perpendicular(&b));
+
+    // projecting onto zero does not produce NaNs
+    let zero = Vector3::new(0.0f64, 0.0, 0.0);
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).project_on(&zero), zero);
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).reject_from(&zero), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_index() {
+    let mut v = Vector4::new(1i, 2i, 3i, 4i);
+    assert_eq!(v[0], v.x);
+    assert_eq!(v[1], v.y);
+    assert_eq!(v[2], v.z);
+    assert_eq!(v[3], v.w);
+
+    // writing through an index updates the field
+    v[2] = 10i;
+    assert_eq!(v.z, 10i);
+    v.w = -5i;
+    assert_eq!(v[3], -5i);
+}
+
+#[test]
+#[should_fail]
+fn test_index_out_of_bounds() {
+    let v = Vector3::new(1i, 2i, 3i);
+    v[3];
+}
+
+#[test]
+fn test_swizzle() {
+    let v = Vector4::new(1i, 2i, 3i, 4i);
+    assert_eq!(v.xyz(), Vector3::new(1i, 2i, 3i));
+    assert_eq!(v.wzy(), Vector3::new(4i, 3i, 2i));
+    assert_eq!(v.zw(), Vector2::new(3i, 4i));
+
+    let v = Vector3::new(1i, 2i, 3i);
+    assert_eq!(v.zyx(), Vector3::new(3i, 2i, 1i));
+    assert_eq!(v.xz(), Vector2::new(1i, 3i));
+
+    assert_eq!(Vector2::new(1i, 2i).yx(), Vector2::new(2i, 1i));
+}
+
+#[test]
+fn test_extend_truncate() {
+    let v2 = Vector2::new(1i, 2i);
+    let v3 = v2.extend(3i);
+    assert_eq!(v3, Vector3::new(1i, 2i, 3i));
+    assert_eq!(v3.z, 3i);
+    assert_eq!(v3.truncate(), v2);
+
+    let v4 = v3.extend(4i);
+    assert_eq!(v4, Vector4::new(1i, 2i, 3i, 4i));
+    assert_eq!(v4.w, 4i);
+    assert_eq!(v4.truncate(), v3);
+    assert_eq!(v4.truncate().truncate(), v2);
+
+    assert_eq!(v4.truncate_n(1), Vector3::new(1i, 3i, 4i));
+}
+
+#[test]
+fn test_min_max_clamp() {
+    let a = Vector2::new(1i, 5i);
+    let b = Vector2::new(3i, 2i);
+    assert_eq!(a.min(&b), Vector2::new(1i, 2i));
+    assert_eq!(a.max(&b), Vector2::new(3i, 5i));
+
+    let a = Vector3::new(-1.0f64, 4.0, 0.5);
+    let b = Vector3::new(2.0f64, -3.0, 0.5);
+    assert_eq!(a.min(&b), Vector3::new(-1.0, -3.0, 0.5));
+    assert_eq!(a.max(&b), Vector3::new(2.0, 4.0, 0.5));
+    assert_eq!(a.comp_min(), -1.0);
+    assert_eq!(a.comp_max(), 4.0);
+
+    // some components below the range, some above, and some inside
+    let v = Vector4::new(-2.0f64, 0.5, 3.0, 1.0);
+    let lo = Vector4::from_value(0.0f64);
+    let hi = Vector4::from_value(1.0f64);
+    assert_eq!(v.clamp(&lo, &hi), Vector4::new(0.0, 0.5, 1.0, 1.0));
+    assert_eq!(v.comp_min(), -2.0);
+    assert_eq!(v.comp_max(), 3.0);
+}
+
+#[test]
+fn test_comp_add_mul() {
+    // the sum and product of the components
+    assert_eq!(Vector3::new(1i, 2i, 3i).comp_add(), 6i);
+    assert_eq!(Vector3::new(1i, 2i, 3i).comp_mul(), 6i);
+
+    assert_eq!(Vector2::new(3.0f64, -4.0).comp_add(), -1.0);
+    assert_eq!(Vector2::new(3.0f64, -4.0).comp_mul(), -12.0);
+    assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).comp_add(), 10.0);
+    assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).comp_mul(), 24.0);
+
+    // the manhattan norm and the volume of a box
+    let v = Vector3::new(-1.0f64, 2.0, -3.0);
+    assert_eq!(v.mul_v(&Vector3::new(-1.0, 1.0, -1.0)).comp_add(), 6.0);
+    assert_eq!(Vector3::new(2.0f64, 3.0, 4.0).comp_mul(), 24.0);
+}
+
+#[test]
+fn test_normalize_to() {
+    let v = Vector3::new(0.0f64, 3.0, 4.0);
+    assert!(v.normalize_to(10.0).approx_eq(&Vector3::new(0.0, 6.0, 8.0)));
+    assert!(v.normalize_to(1.0).approx_eq(&Vector3::new(0.0, 0.6, 0.8)));
+
+    // the zero vector has no direction to preserve
+    let z = Vector3::new(0.0f64, 0.0, 0.0);
+    assert_eq!(z.normalize_to(5.0), z);
+    assert_eq!(z.normalize(), z);
+    let mut m = z;
+    m.normalize_self();
+    assert_eq!(m, z);
+}
+
+#[test]
+fn test_limit_length() {
+    let v = Vector2::new(3.0f64, 4.0);
+    assert!(v.limit_length(2.5).approx_eq(&Vector2::new(1.5, 2.0)));
+    assert!(v.limit_length(10.0).approx_eq(&v));
+    assert!(v.limit_length(5.0).approx_eq(&v));
+    assert_eq!(Vector2::new(0.0f64, 0.0).limit_length(1.0), Vector2::new(0.0, 0.0));
+}
+
+#[test]
+fn test_cast() {
+    let v = Vector3::new(0.1f32, -2.5, 1e6);
+    let w: Vector3<f64> = v.cast();
+    assert_eq!(w.cast::<f32>(), v);
+    assert!(w.approx_eq_eps(&Vector3::new(0.1f64, -2.5, 1e6), &1e-6));
+
+    // integer targets truncate towards zero
+    assert_eq!(Vector4::new(1.9f64, -1.9, 0.0, 42.0).cast::<int>(), Vector4::new(1i, -1, 0, 42));
+}
+
+#[test]
+fn test_from_iter() {
+    let v: Vector4<int> = range(1i, 5).collect();
+    assert_eq!(v, Vector4::new(1i, 2, 3, 4));
+
+    // any extra elements are ignored
+    let v: Vector2<int> = range(1i, 5).collect();
+    assert_eq!(v, Vector2::new(1i, 2));
+}
+
+#[test]
+#[should_fail]
+fn test_from_iter_too_short() {
+    let _: Vector3<int> = range(1i, 3).collect();
+}
+
+#[test]
+fn test_vector4_ops_match_scalar() {
+    use std::rand::{IsaacRng, Rng};
+
+    let mut rng = IsaacRng::new_unseeded();
+    for _ in range(0u, 100) {
+        let a: Vector4<f32> = rng.gen();
+        let b: Vector4<f32> = rng.gen();
+
+        assert_eq!(a.add_v(&b), Vector4::new(a.x + b.x, a.y + b.y, a.z + b.z, a.w + b.w));
+        assert_eq!(a.sub_v(&b), Vector4::new(a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w));
+        assert_eq!(a.mul_v(&b), Vector4::new(a.x * b.x, a.y * b.y, a.z * b.z, a.w * b.w));
+        assert_eq!(a.dot(&b), a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w);
+    }
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashMap;
+
+    let mut voxels = HashMap::new();
+    voxels.insert(Vector3::new(0i, 0, 0), "origin");
+    voxels.insert(Vector3::new(1i, -2, 3), "a");
+    voxels.insert(Vector3::new(3i, -2, 1), "b");
+
+    assert_eq!(voxels.get(&Vector3::new(0i, 0, 0)), Some(&"origin"));
+    assert_eq!(voxels.get(&Vector3::new(1i, -2, 3)), Some(&"a"));
+    assert_eq!(voxels.get(&Vector3::new(3i, -2, 1)), Some(&"b"));
+    assert_eq!(voxels.get(&Vector3::new(1i, 2, 3)), None);
+}
+
+#[test]
+fn test_polar() {
+    let (r, a) = Vector2::new(2.0f64, 0.0).to_polar();
+    assert_eq!((r, a.s), (2.0, 0.0));
+    let (r, a) = Vector2::new(0.0f64, 2.0).to_polar();
+    assert_eq!((r, a.s), (2.0, f64::consts::FRAC_PI_2));
+    let (r, a) = Vector2::new(-2.0f64, 0.0).to_polar();
+    assert_eq!((r, a.s), (2.0, f64::consts::PI));
+    let (r, a) = Vector2::new(0.0f64, -2.0).to_polar();
+    assert_eq!((r, a.s), (2.0, -f64::consts::FRAC_PI_2));
+
+    let (r, a) = Vector2::new(0.0f64, 0.0).to_polar();
+    assert_eq!((r, a.s), (0.0, 0.0));
+
+    let v = Vector2::new(-3.0f64, 4.0);
+    let (r, a) = v.to_polar();
+    assert_eq!(r, 5.0);
+    assert!(Vector2::from_polar(r, a).approx_eq(&v));
+    assert!(Vector2::from_polar(1.0f64, rad(f64::consts::FRAC_PI_2)).approx_eq(&Vector2::unit_y()));
+}
+
+#[test]
+fn test_rand_unit() {
+    let mut rng = IsaacRng::new_unseeded();
+    let n = 10000u;
+    let mut sum = Vector3::new(0.0f64, 0.0, 0.0);
+    for _ in range(0, n) {
+        let v: Vector3<f64> = Vector3::rand_unit(&mut rng);
+        assert!(v.length().approx_eq(&1.0));
+        sum.add_self_v(&v);
+    }
+    // the samples should be spread evenly, so their mean is close to zero
+    assert!(sum.div_s(n as f64).length() < 0.05);
+}
+
+#[test]
+fn test_rand() {
+    let mut rng = IsaacRng::new_unseeded();
+    for _ in range(0u, 100) {
+        let v: Vector4<f64> = rng.gen();
+        assert!(v.x >= 0.0 && v.x < 1.0);
+        assert!(v.y >= 0.0 && v.y < 1.0);
+        assert!(v.z >= 0.0 && v.z < 1.0);
+        assert!(v.w >= 0.0 && v.w < 1.0);
+    }
+}
+
+#[test]
+fn test_default() {
+    let v: Vector2<f64> = Default::default();
+    assert_eq!(v, Vector2::new(0.0, 0.0));
+    let v: Vector3<f64> = Default::default();
+    assert_eq!(v, Vector3::new(0.0, 0.0, 0.0));
+    let v: Vector4<int> = Default::default();
+    assert_eq!(v, Vector4::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_ord() {
+    let mut v = vec![Vector2::new(1i, 2), Vector2::new(0, 5), Vector2::new(1, -1), Vector2::new(0, 3)];
+    v.sort();
+    assert_eq!(v, vec![Vector2::new(0i, 3), Vector2::new(0, 5), Vector2::new(1, -1), Vector2::new(1, 2)]);
+
+    assert!(Vector3::new(1i, 2, 3) < Vector3::new(1, 2, 4));
+    assert!(Vector3::new(1i, 2, 3) > Vector3::new(0, 9, 9));
+    assert!(Vector4::new(1i, 2, 3, 4) <= Vector4::new(1, 2, 3, 4));
+
+    // float vectors are only partially ordered
+    assert!(Vector2::new(1.0f64, 0.0) < Vector2::new(1.0, 0.5));
+    assert!(!(Vector2::new(f64::NAN, 0.0) < Vector2::new(1.0, 0.5)));
+}
+
+#[test]
+fn test_zip_with() {
+    let a = Vector3::new(1.0f64, -2.0, 3.0);
+    let b = Vector3::new(0.5f64, 4.0, -3.0);
+    let max = a.zip_with(&b, |x, y| if x > y { x } else { y });
+    assert_eq!(max, Vector3::new(1.0, 4.0, 3.0));
+    assert_eq!(max, a.max(&b));
+
+    assert_eq!(Vector2::new(2.0f64, 3.0).zip_with(&Vector2::new(3.0, 2.0), |x, y| x.powf(y)),
+               Vector2::new(8.0, 9.0));
+    assert_eq!(Vector4::new(1i, 2, 3, 4).zip_with(&Vector4::new(4, 3, 2, 1), |x, y| x * 10 + y),
+               Vector4::new(14, 23, 32, 41));
+}
+
+#[test]
+fn test_fold() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    assert_eq!(v.fold(0.0, |acc, &x| acc + x * x), v.dot(&v));
+    let v = Vector4::new(1i, 2, 3, 4);
+    assert_eq!(v.fold(0, |acc, &x| acc * 10 + x), 1234);
+}
+
+#[test]
+fn test_magnitude() {
+    let v = Vector3::new(3.0f64, 4.0, 0.0);
+    assert_eq!(v.magnitude(), 5.0);
+    assert_eq!(v.magnitude2(), 25.0);
+    assert_eq!(v.magnitude(), v.length());
+    assert_eq!(v.normalize().magnitude(), 1.0);
+
+    assert_eq!(Vector2::new(3i, 4).magnitude2(), 25);
+    assert_eq!(Vector4::new(0.0f64, 3.0, 0.0, -4.0).magnitude(), 5.0);
+}
+
+#[test]
+fn test_perp() {
+    let a = Vector2::new(2.0f64, 1.0);
+    let b = Vector2::new(-1.0f64, 3.0);
+    assert!(a.perp_dot(&b) > 0.0);
+    assert!(b.perp_dot(&a) < 0.0);
+    assert_eq!(a.perp_dot(&a.mul_s(-3.0)), 0.0);
+    assert_eq!(a.perp_dot(&b), a.extend(0.0).cross(&b.extend(0.0)).z);
+
+    assert_eq!(Vector2::new(1i, 0).perp(), Vector2::new(0, 1));
+    assert_eq!(Vector2::new(0i, 1).perp(), Vector2::new(-1, 0));
+    assert_eq!(a.perp().dot(&a), 0.0);
+    assert_eq!(a.perp_dot(&a.perp()), a.length2());
+}
+
+#[test]
+fn test_triple_product() {
+    let (x, y, z) = (Vector3::<f64>::unit_x(), Vector3::<f64>::unit_y(), Vector3::<f64>::unit_z());
+    assert_eq!(x.triple_product(&y, &z), 1.0);
+    assert_eq!(y.triple_product(&x, &z), -1.0);
+    assert_eq!(Vector3::new(2i, 0, 0).triple_product(&Vector3::new(0, 3, 0), &Vector3::new(1, 1, 4)), 24);
+
+    // coplanar vectors enclose no volume
+    let a = Vector3::new(1.0f64, 2.0, 0.0);
+    let b = Vector3::new(-3.0f64, 1.0, 0.0);
+    assert_eq!(a.triple_product(&b, &a.add_v(&b)), 0.0);
+
+    // a × (b × c) = b(a · c) - c(a · b)
+    let c = Vector3::new(0.5f64, -1.0, 2.0);
+    assert_eq!(a.vector_triple_product(&b, &c), b.mul_s(a.dot(&c)).sub_v(&c.mul_s(a.dot(&b))));
+}
+
+#[test]
+fn test_unit_vectors() {
+    assert_eq!(Vector2::<f64>::unit_x(), Vector2::new(1.0, 0.0));
+    assert_eq!(Vector2::<f64>::unit_y(), Vector2::new(0.0, 1.0));
+
+    assert_eq!(Vector3::<f64>::unit_x(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(Vector3::<f64>::unit_y(), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(Vector3::<f64>::unit_z(), Vector3::new(0.0, 0.0, 1.0));
+
+    assert_eq!(Vector4::<f64>::unit_x(), Vector4::new(1.0, 0.0, 0.0, 0.0));
+    assert_eq!(Vector4::<f64>::unit_y(), Vector4::new(0.0, 1.0, 0.0, 0.0));
+    assert_eq!(Vector4::<f64>::unit_z(), Vector4::new(0.0, 0.0, 1.0, 0.0));
+    assert_eq!(Vector4::<f64>::unit_w(), Vector4::new(0.0, 0.0, 0.0, 1.0));
+
+    for v in [Vector3::<f64>::unit_x(), Vector3::unit_y(), Vector3::unit_z()].iter() {
+        assert_eq!(v.magnitude(), 1.0);
+    }
+    assert_eq!(Vector4::<f64>::unit_w().magnitude(), 1.0);
+}
+
+#[test]
+fn test_zero_one() {
+    assert_eq!(Vector2::<int>::zero(), Vector2::new(0, 0));
+    assert_eq!(Vector3::<f64>::zero(), Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(Vector4::<f64>::zero(), Vector4::from_value(0.0));
+    assert_eq!(Vector2::<int>::one(), Vector2::new(1, 1));
+    assert_eq!(Vector3::<f64>::one(), Vector3::from_value(1.0));
+    assert_eq!(Vector4::<f64>::one(), Vector4::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_rounding() {
+    let v = Vector4::new(-1.5f64, -0.25, 0.5, 2.75);
+    assert_eq!(v.abs(), Vector4::new(1.5, 0.25, 0.5, 2.75));
+    assert_eq!(v.floor(), Vector4::new(-2.0, -1.0, 0.0, 2.0));
+    assert_eq!(v.ceil(), Vector4::new(-1.0, -0.0, 1.0, 3.0));
+    assert_eq!(v.round(), Vector4::new(-2.0, -0.0, 1.0, 3.0));
+
+    let v = Vector3::new(-1.5f64, -0.25, 2.75);
+    assert_eq!(v.floor(), Vector3::new(v.x.floor(), v.y.floor(), v.z.floor()));
+    assert_eq!(v.round(), Vector3::new(v.x.round(), v.y.round(), v.z.round()));
+    let v = Vector2::new(-1.5f64, 0.25);
+    assert_eq!(v.abs(), Vector2::new(v.x.abs(), v.y.abs()));
+    assert_eq!(v.ceil(), Vector2::new(v.x.ceil(), v.y.ceil()));
+}
+
+#[test]
+fn test_normalize_self() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    let mut w = v;
+    w.normalize_self();
+    assert_eq!(w, v.normalize());
+
+    let v = Vector4::new(1.0f64, -2.0, 3.0, 0.5);
+    let mut w = v;
+    w.normalize_self_to(3.0);
+    assert_eq!(w, v.normalize_to(3.0));
+
+    let mut z = Vector2::new(0.0f64, 0.0);
+    z.normalize_self();
+    assert_eq!(z, Vector2::new(0.0, 0.0));
+}
+
+fn normalized_dot<S: BaseFloat, V: EuclideanVector<S>>(a: &V, b: &V) -> S {
+    a.normalize().dot(&b.normalize())
+}
+
+fn generic_normal<S: BaseFloat, V: Cross<S> + EuclideanVector<S>>(a: &V, b: &V) -> V {
+    a.cross(b).normalize()
+}
+
+#[test]
+fn test_generic_products() {
+    assert!(normalized_dot(&Vector2::new(2.0f64, 0.0), &Vector2::new(3.0, 3.0)).approx_eq(&0.5f64.sqrt()));
+    assert!(normalized_dot(&Vector3::new(0.0f64, 0.0, 2.0), &Vector3::new(1.0, 0.0, -1.0)).approx_eq(&-0.5f64.sqrt()));
+    assert!(normalized_dot(&Vector4::new(1.0f64, 0.0, 0.0, 0.0), &Vector4::new(0.0, 0.0, 0.0, 5.0)).approx_eq(&0.0));
+
+    assert_eq!(generic_normal(&Vector3::new(2.0f64, 0.0, 0.0), &Vector3::new(0.0, 3.0, 0.0)), Vector3::unit_z());
+}
+
+#[test]
+fn test_saturating() {
+    let a = Vector2::new(250u8, 10);
+    let b = Vector2::new(10u8, 20);
+    assert_eq!(a.saturating_add_v(&b), Vector2::new(255, 30));
+    assert_eq!(b.saturating_sub_v(&a), Vector2::new(0, 10));
+
+    let a = Vector3::new(i32::MAX, i32::MIN, 0);
+    let one = Vector3::new(1i32, 1, 1);
+    assert_eq!(a.saturating_add_v(&one), Vector3::new(i32::MAX, i32::MIN + 1, 1));
+    assert_eq!(a.saturating_sub_v(&one), Vector3::new(i32::MAX - 1, i32::MIN, -1));
+}
+
+#[test]
+fn test_checked() {
+    let a = Vector4::new(100u8, 0, 5, 16);
+    assert_eq!(a.checked_add_v(&Vector4::new(100, 1, 5, 16)), Some(Vector4::new(200, 1, 10, 32)));
+    assert_eq!(a.checked_add_v(&Vector4::new(200, 1, 5, 16)), None);
+    assert_eq!(a.checked_sub_v(&Vector4::new(100, 0, 5, 16)), Some(Vector4::new(0, 0, 0, 0)));
+    assert_eq!(a.checked_sub_v(&Vector4::new(0, 1, 0, 0)), None);
+    assert_eq!(a.checked_mul_v(&Vector4::new(2, 7, 3, 15)), Some(Vector4::new(200, 0, 15, 240)));
+    assert_eq!(a.checked_mul_v(&Vector4::new(1, 1, 1, 16)), None);
+}
+
+#[test]
+fn test_neg() {
+    assert_eq!(-Vector2::new(1i, -2), Vector2::new(-1i, 2));
+    assert_eq!(-Vector3::new(1i, -2, 3), Vector3::new(-1i, 2, -3));
+    assert_eq!(-Vector4::new(1.0f64, -2.0, 3.0, -4.0), Vector4::new(-1.0f64, 2.0, -3.0, 4.0));
+
+    let v = Vector3::new(1i, -2, 3);
+    assert_eq!(-(-v), v);
+    assert_eq!(-v, Vector3::zero().sub_v(&v));
+
+    let mut w = v;
+    w.neg_self();
+    assert_eq!(w, -v);
+}
+
+#[test]
+fn test_operators() {
+    let a = Vector3::new(1.0f64, -2.0, 3.0);
+    let b = Vector3::new(4.0f64, 5.0, -6.0);
+    assert_eq!(a + b, a.add_v(&b));
+    assert_eq!(a - b, a.sub_v(&b));
+    assert_eq!(a * b, a.mul_v(&b));
+    assert_eq!(a * 2.0, a.mul_s(2.0));
+
+    let c = Vector4::new(1i, 2, 3, 4);
+    let d = Vector4::new(-1i, 0, 5, 2);
+    assert_eq!(c + d, c.add_v(&d));
+    assert_eq!(c - d, c.sub_v(&d));
+    assert_eq!(c * 3, c.mul_s(3));
+    assert_eq!(Vector2::new(1i, 2) * 3, Vector2::new(3i, 6));
+}
+
+#[test]
+fn test_tuple_round_trip() {
+    let (x, y) = Vector2::new(1i, 2).into_tuple();
+    assert_eq!((x, y), (1i, 2));
+    assert_eq!(Vector2::from_tuple((1i, 2)), Vector2::new(1i, 2));
+
+    let v = Vector3::new(1.5f64, -2.0, 3.0);
+    let (x, y, z) = v.into_tuple();
+    assert_eq!((x, y, z), (1.5f64, -2.0, 3.0));
+    assert_eq!(Vector3::from_tuple(v.into_tuple()), v);
+
+    let v = Vector4::new(1i, 2, 3, 4);
+    assert_eq!(v.into_tuple(), (1i, 2, 3, 4));
+    assert_eq!(Vector4::from_tuple(v.into_tuple()), v);
+}
+
+#[test]
+fn test_midpoint() {
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(3.0f64, -2.0, 0.0);
+    assert_eq!(midpoint(&a, &b), Vector3::new(2.0, 0.0, 1.5));
+    assert_eq!(midpoint(&a, &a), a);
+    assert_eq!(midpoint(&Vector2::new(0.0f64, 0.0), &Vector2::new(1.0, -4.0)), Vector2::new(0.5, -2.0));
+}
+
+#[test]
+fn test_centroid() {
+    let tri = [Vector2::new(0.0f64, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)];
+    assert!(centroid(tri.as_slice()).approx_eq(&Vector2::new(1.0 / 3.0, 1.0 / 3.0)));
+
+    let tri = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+    assert!(centroid(tri.as_slice()).approx_eq(&Vector3::from_value(1.0f64 / 3.0)));
+
+    let points = [Vector3::new(1.0f64, 2.0, 3.0)];
+    assert_eq!(centroid(points.as_slice()), points[0]);
+
+    let empty: [Vector3<f64>; 0] = [];
+    assert_eq!(centroid(empty.as_slice()), Vector3::zero());
+}
+
+#[test]
+fn test_barycentric() {
+    let a = Vector2::new(0.0f64, 0.0);
+    let b = Vector2::new(2.0f64, 0.0);
+    let c = Vector2::new(0.0f64, 3.0);
+    assert_eq!(barycentric(&a, &a, &b, &c), Some((1.0, 0.0, 0.0)));
+    assert_eq!(barycentric(&b, &a, &b, &c), Some((0.0, 1.0, 0.0)));
+    assert_eq!(barycentric(&c, &a, &b, &c), Some((0.0, 0.0, 1.0)));
+
+    let (u, v, w) = barycentric(&centroid([a, b, c].as_slice()), &a, &b, &c).unwrap();
+    assert!(u.approx_eq(&(1.0 / 3.0)));
+    assert!(v.approx_eq(&(1.0 / 3.0)));
+    assert!(w.approx_eq(&(1.0 / 3.0)));
+
+    // points outside the triangle have a negative weight
+    let (u, _, _) = barycentric(&Vector2::new(2.0, 3.0), &a, &b, &c).unwrap();
+    assert!(u < 0.0);
+
+    // degenerate triangles
+    assert_eq!(barycentric(&a, &a, &b, &b.mul_s(2.0)), None);
+    assert_eq!(barycentric(&a, &a, &a, &a), None);
+}
+
+#[test]
+fn test_barycentric3() {
+    let a = Vector3::new(1.0f64, 0.0, 0.0);
+    let b = Vector3::new(0.0f64, 1.0, 0.0);
+    let c = Vector3::new(0.0f64, 0.0, 1.0);
+    assert_eq!(barycentric3(&a, &a, &b, &c), Some((1.0, 0.0, 0.0)));
+    assert_eq!(barycentric3(&b, &a, &b, &c), Some((0.0, 1.0, 0.0)));
+    assert_eq!(barycentric3(&c, &a, &b, &c), Some((0.0, 0.0, 1.0)));
+
+    let (u, v, w) = barycentric3(&centroid([a, b, c].as_slice()), &a, &b, &c).unwrap();
+    assert!(u.approx_eq(&(1.0 / 3.0)));
+    assert!(v.approx_eq(&(1.0 / 3.0)));
+    assert!(w.approx_eq(&(1.0 / 3.0)));
+
+    // the weights reconstruct the point
+    let p = a.mul_s(0.2).add_v(&b.mul_s(0.5)).add_v(&c.mul_s(0.3));
+    let (u, v, w) = barycentric3(&p, &a, &b, &c).unwrap();
+    assert!(a.mul_s(u).add_v(&b.mul_s(v)).add_v(&c.mul_s(w)).approx_eq(&p));
+
+    assert_eq!(barycentric3(&a, &a, &b, &b), None);
+}